@@ -1,8 +1,8 @@
 #![no_std]
 
-use soroban_sdk::{contractimpl, contracttype, symbol, vec, Env, BigInt, BytesN, Vec, Symbol, Address, RawVal};
+use soroban_sdk::{contractimpl, contracttype, symbol, vec, Env, BigInt, BytesN, Vec, Symbol, Address, RawVal, IntoVal};
 
-use soroban_auth::{Identifier, Signature};
+use soroban_auth::{verify, Identifier, Signature};
 
 mod token {
     soroban_sdk::contractimport!(file = "./soroban_token_spec.wasm");
@@ -12,43 +12,557 @@ mod token {
 #[contracttype]
 pub enum DataKey {
     Name,
-    TkContract,
-    ChildRecip // Vec<Recipient>
+    Tokens,
+    ChildRecip, // Vec<Recipient>
+    Guardian,
+    Paused,
+    Nonce(Identifier),
+    SchemaV,
+    Owner,
+    Retained(BytesN<32>),
+    Parents,
+    Limits,
+    Held(BytesN<32>),
+    PullMode,
+    Accrued(AccruedKey),
+    ChildrenV,
+    Receipt(u32),
+    ReceiptId,
+    DonorRcpts(Identifier),
+    DonorTotal(DonorKey),
+    Raised(BytesN<32>)
 }
 
+const SCHEMA_VERSION: u32 = 1;
+
+// How deep the children are walked when checking a configuration for cycles.
+const MAX_CHECK_DEPTH: u32 = 8;
+
+// Shares in the cascade tree are expressed in parts per million of the original donation.
+const FULL_SHARE: u32 = 1_000_000;
+
+const DEFAULT_MAX_DEPTH: u32 = 4;
+const DEFAULT_MAX_FANOUT: u32 = 10;
+
+// The depth is the number of parent cascades a donation went through, counted from the `parents`.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Limits {
+    pub depth: u32,
+    pub fanout: u32,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct AccruedKey {
+    pub recipient: Address,
+    pub token: BytesN<32>,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct DonorKey {
+    pub donor: Identifier,
+    pub token: BytesN<32>,
+}
+
+// The children version is the configuration of children the donation was split with.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Receipt {
+    pub donor: Identifier,
+    pub token: BytesN<32>,
+    pub amount: BigInt,
+    pub time: u64,
+    pub children_v: u32,
+}
+
+// A recipient without tokens accepts every token allowed by the cascade, the shares of the
+// tokens it doesn't accept are retained.
 #[derive(Clone, Debug)]
 #[contracttype]
 pub struct Recipient {
     name: Symbol,
     dest: Address,
     percentage: u32,
+    tokens: Vec<BytesN<32>>,
+}
+
+// The depth is 1 for the direct children of the cascade where the tree starts.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Node {
+    pub name: Symbol,
+    pub dest: Address,
+    pub parent: Address,
+    pub percentage: u32,
+    pub share: u32,
+    pub depth: u32,
+}
+
+// The depth is 1 for the direct children of the cascade where the donation starts.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Payout {
+    pub name: Symbol,
+    pub dest: Address,
+    pub parent: Address,
+    pub amount: BigInt,
+    pub depth: u32,
+}
+
+// AUTHORIZATION
+fn read_nonce(env: &Env, id: &Identifier) -> BigInt {
+    let key = DataKey::Nonce(id.clone());
+    env.data()
+        .get(key)
+        .unwrap_or(Ok(BigInt::zero(&env)))
+        .unwrap()
+}
+
+fn verify_and_consume_nonce(env: &Env, auth: &Signature, expected_nonce: &BigInt) {
+    match auth {
+        Signature::Invoker => {
+            if BigInt::zero(&env) != expected_nonce {
+                panic!("nonce should be zero for Invoker")
+            }
+            return;
+        }
+        _ => {}
+    }
+
+    let id = auth.identifier(&env);
+    let nonce = read_nonce(&env, &id);
+
+    if nonce != expected_nonce {
+        panic!("incorrect nonce")
+    }
+
+    env.data().set(DataKey::Nonce(id), &nonce + 1);
+}
+
+// GUARDIAN
+fn get_guardian(env: &Env) -> Identifier {
+    let key = DataKey::Guardian;
+    env.data().get(key).unwrap().unwrap()
+}
+
+fn set_guardian(env: &Env, guardian: &Identifier) {
+    env.data().set(DataKey::Guardian, guardian);
+}
+
+fn check_guardian(env: &Env, auth: &Signature, nonce: &BigInt) {
+    if auth.identifier(&env) != get_guardian(&env) {
+        panic!("Only the guardian can perform this action");
+    }
+
+    verify_and_consume_nonce(&env, &auth, &nonce);
+}
+
+// OWNER
+fn get_owner(env: &Env) -> Identifier {
+    let key = DataKey::Owner;
+    env.data().get(key).unwrap().unwrap()
+}
+
+fn set_owner(env: &Env, owner: &Identifier) {
+    env.data().set(DataKey::Owner, owner);
+}
+
+fn check_owner(env: &Env, auth: &Signature, nonce: &BigInt) {
+    if auth.identifier(&env) != get_owner(&env) {
+        panic!("Only the owner can perform this action");
+    }
+
+    verify_and_consume_nonce(&env, &auth, &nonce);
+}
+
+fn transfer_ownership(env: &Env, new_owner: &Identifier) {
+    let previous_owner = get_owner(&env);
+    set_owner(&env, &new_owner);
+
+    env.events().publish((symbol!("owner"),), (previous_owner, new_owner.clone()));
+}
+
+// PAUSE
+fn is_paused(env: &Env) -> bool {
+    let key = DataKey::Paused;
+    env.data().get(key).unwrap_or(Ok(false)).unwrap()
+}
+
+fn set_paused(env: &Env, paused: bool) {
+    env.data().set(DataKey::Paused, paused);
+
+    if paused {
+        env.events().publish((symbol!("paused"),), get_guardian(&env));
+    } else {
+        env.events().publish((symbol!("unpaused"),), get_guardian(&env));
+    }
+}
+
+fn check_not_paused(env: &Env) {
+    if is_paused(&env) {
+        panic!("The cascade is paused");
+    }
+}
+
+// INITIALIZATION
+fn check_not_initialized(env: &Env) {
+    if env.data().has(DataKey::Owner) {
+        panic!("The cascade is already initialized");
+    }
+}
+
+// SCHEMA VERSION
+fn get_schema_version(env: &Env) -> u32 {
+    let key = DataKey::SchemaV;
+    env.data().get(key).unwrap().unwrap()
+}
+
+fn set_schema_version(env: &Env, version: u32) {
+    env.data().set(DataKey::SchemaV, version);
 }
 
 // CHILDREN
 fn get_children(env: &Env) -> Vec<Recipient> {
     let key = DataKey::ChildRecip;
-    env.data().get(key).unwrap().unwrap()
+    env.data()
+        .get(key)
+        .unwrap_or(Ok(vec![&env]))
+        .unwrap()
+}
+
+fn check_percentages(children: &Vec<Recipient>) {
+    let mut total: u32 = 0;
+
+    for child in children.iter() {
+        total = total.saturating_add(child.unwrap().percentage);
+    }
+
+    if total > 100 {
+        panic!("The children percentages can't add up to more than 100");
+    }
+}
+
+// Contract children only cascade the tokens they allow, so an empty list takes the child's tokens and
+// an explicit one must be allowed by the child. The tokens are read when the children are set.
+fn resolve_child_tokens(env: &Env, children: &Vec<Recipient>) -> Vec<Recipient> {
+    let mut resolved: Vec<Recipient> = vec![&env];
+
+    for child in children.iter() {
+        let mut recipient = child.unwrap();
+
+        if let Address::Contract(contract_id) = &recipient.dest {
+            let child_tokens: Vec<BytesN<32>> = env.invoke_contract(&contract_id, &symbol!("tokens"), vec![&env]);
+
+            if child_tokens.is_empty() {
+                panic!("The child {:?} doesn't allow any token", recipient.name);
+            }
+
+            if recipient.tokens.is_empty() {
+                recipient.tokens = child_tokens;
+            } else {
+                for token in recipient.tokens.iter() {
+                    if !child_tokens.contains(token.unwrap()) {
+                        panic!("The child {:?} doesn't allow every token it's given", recipient.name);
+                    }
+                }
+            }
+        }
+
+        resolved.push_back(recipient);
+    }
+
+    resolved
+}
+
+// Walks the children of the new configuration through their `g_children` queries, rejecting any
+// path that leads back to a cascade already in it.
+fn check_cycles(env: &Env, children: &Vec<Recipient>) {
+    let path: Vec<Address> = vec![
+        &env,
+        Address::Contract(env.current_contract())
+    ];
+
+    walk_children(&env, &children, &path);
+}
+
+fn walk_children(env: &Env, children: &Vec<Recipient>, path: &Vec<Address>) {
+    check_walk_depth(&path);
+
+    for child in children.iter() {
+        let recipient = child.unwrap();
+
+        check_not_circular(&path, &recipient);
+
+        if let Address::Contract(contract_id) = &recipient.dest {
+            let grandchildren: Vec<Recipient> = env.invoke_contract(&contract_id, &symbol!("g_children"), vec![&env]);
+
+            let mut child_path = path.clone();
+            child_path.push_back(recipient.dest.clone());
+
+            walk_children(&env, &grandchildren, &child_path);
+        }
+    }
+}
+
+// The cycle protections shared by the donations and every walk through the cascade.
+fn check_not_circular(path: &Vec<Address>, recipient: &Recipient) {
+    if path.contains(&recipient.dest) {
+        panic!("Circular cascading aren't allowed, verify the children from {:?} in the path {:?}", recipient.name, path);
+    }
+}
+
+fn check_walk_depth(path: &Vec<Address>) {
+    if path.len() > MAX_CHECK_DEPTH {
+        panic!("The cascade is too deep to walk, stopped at the path {:?}", path);
+    }
 }
 
 fn set_children(env: &Env, new_children: &Vec<Recipient>) {
     env.data().set(DataKey::ChildRecip, new_children);
+    env.data().set(DataKey::ChildrenV, get_children_version(&env) + 1);
 }
 
-// TOKEN CONTRACT
-fn get_token_contract_id(env: &Env) -> BytesN<32> {
-    let key = DataKey::TkContract;
-    env.data().get(key).unwrap().unwrap()
+// Increases on every change of the children, so receipts point to the configuration they used.
+fn get_children_version(env: &Env) -> u32 {
+    let key = DataKey::ChildrenV;
+    env.data().get(key).unwrap_or(Ok(0)).unwrap()
+}
+
+// TOKENS
+fn get_tokens(env: &Env) -> Vec<BytesN<32>> {
+    let key = DataKey::Tokens;
+    env.data()
+        .get(key)
+        .unwrap_or(Ok(vec![&env]))
+        .unwrap()
+}
+
+fn set_tokens(env: &Env, tokens: &Vec<BytesN<32>>) {
+    env.data().set(DataKey::Tokens, tokens);
+}
+
+fn check_token(env: &Env, token: &BytesN<32>) {
+    if !get_tokens(&env).contains(token) {
+        panic!("The token isn't allowed by the cascade");
+    }
+}
+
+fn accepts_token(recipient: &Recipient, token: &BytesN<32>) -> bool {
+    recipient.tokens.is_empty() || recipient.tokens.contains(token)
+}
+
+// PARENTS
+fn get_parents(env: &Env) -> Vec<BytesN<32>> {
+    let key = DataKey::Parents;
+    env.data()
+        .get(key)
+        .unwrap_or(Ok(vec![&env]))
+        .unwrap()
+}
+
+fn set_parents(env: &Env, parents: &Vec<BytesN<32>>) {
+    env.data().set(DataKey::Parents, parents);
+}
+
+// Parent cascades are verified through the invoker. A child can't query its parent's children while
+// the parent is donating since contracts can't be re-entered, so the parents must be allowlisted.
+fn check_parent(env: &Env, parents: &Vec<Address>) {
+    let parent_id = match env.invoker() {
+        Address::Contract(contract_id) => contract_id,
+        Address::Account(_) => panic!("Only registered parents can donate to the children"),
+    };
+
+    if !get_parents(&env).contains(&parent_id) {
+        panic!("Only registered parents can donate to the children");
+    }
+
+    match parents.last() {
+        Some(Ok(Address::Contract(last_parent))) if last_parent == parent_id => {},
+        _ => panic!("The parents don't end with the invoking cascade"),
+    }
+}
+
+// LIMITS
+fn get_limits(env: &Env) -> Limits {
+    let key = DataKey::Limits;
+    env.data()
+        .get(key)
+        .unwrap_or(Ok(Limits { depth: DEFAULT_MAX_DEPTH, fanout: DEFAULT_MAX_FANOUT }))
+        .unwrap()
+}
+
+fn set_limits(env: &Env, limits: &Limits) {
+    if limits.depth == 0 || limits.fanout == 0 {
+        panic!("The cascade limits must be greater than zero");
+    }
+
+    if get_children(&env).len() > limits.fanout {
+        panic!("The cascade already has more children than the new fan-out limit");
+    }
+
+    env.data().set(DataKey::Limits, limits);
+}
+
+fn check_fanout(env: &Env, children: &Vec<Recipient>) {
+    let fanout = get_limits(&env).fanout;
+
+    if children.len() > fanout {
+        panic!("The cascade can't have more than {} children", fanout);
+    }
+}
+
+// HELD
+// Donations reaching the depth limit are held here until someone flushes them to the children.
+fn get_held(env: &Env, token: &BytesN<32>) -> BigInt {
+    let key = DataKey::Held(token.clone());
+    env.data()
+        .get(key)
+        .unwrap_or(Ok(BigInt::zero(&env)))
+        .unwrap()
+}
+
+fn hold_donation(env: &Env, token: &BytesN<32>, amount: &BigInt) {
+    env.data().set(DataKey::Held(token.clone()), get_held(&env, &token) + amount);
+    env.events().publish((symbol!("held"), token.clone()), amount.clone());
+}
+
+fn flush_held(env: &Env, token: &BytesN<32>) {
+    check_not_paused(&env);
+
+    let held = get_held(&env, &token);
+
+    if held == BigInt::zero(&env) {
+        panic!("There aren't held donations to flush");
+    }
+
+    env.data().set(DataKey::Held(token.clone()), BigInt::zero(&env));
+
+    let parents: Vec<Address> = vec![
+        &env,
+        Address::Contract(env.current_contract())
+    ];
+
+    apply_children_donations(&env, &token, &held, &parents);
+}
+
+// RETAINED
+// The share of every donation that isn't sent to the children, kept apart from any other balance.
+fn get_retained(env: &Env, token: &BytesN<32>) -> BigInt {
+    let key = DataKey::Retained(token.clone());
+    env.data()
+        .get(key)
+        .unwrap_or(Ok(BigInt::zero(&env)))
+        .unwrap()
+}
+
+fn set_retained(env: &Env, token: &BytesN<32>, amount: &BigInt) {
+    env.data().set(DataKey::Retained(token.clone()), amount);
+}
+
+fn withdraw_retained(env: &Env, token: &BytesN<32>, to: &Identifier, amount: &BigInt) {
+    check_not_paused(&env);
+
+    let retained = get_retained(&env, &token);
+
+    if amount > &retained {
+        panic!("The amount is greater than the retained balance");
+    }
+
+    set_retained(&env, &token, &(retained - amount));
+
+    let client = token::Client::new(&env, &token);
+
+    client.xfer(
+        &Signature::Invoker,
+        &BigInt::zero(&env),
+        &to,
+        &amount
+    );
+
+    env.events().publish((symbol!("withdrawn"), token.clone()), (to.clone(), amount.clone()));
+}
+
+// PULL MODE
+// In pull mode the children's shares stay in this contract until they're withdrawn or poked,
+// so a failing child can't revert the whole donation.
+fn is_pull_mode(env: &Env) -> bool {
+    let key = DataKey::PullMode;
+    env.data().get(key).unwrap_or(Ok(false)).unwrap()
+}
+
+fn get_accrued(env: &Env, recipient: &Address, token: &BytesN<32>) -> BigInt {
+    let key = DataKey::Accrued(AccruedKey { recipient: recipient.clone(), token: token.clone() });
+    env.data()
+        .get(key)
+        .unwrap_or(Ok(BigInt::zero(&env)))
+        .unwrap()
+}
+
+fn set_accrued(env: &Env, recipient: &Address, token: &BytesN<32>, amount: &BigInt) {
+    let key = DataKey::Accrued(AccruedKey { recipient: recipient.clone(), token: token.clone() });
+    env.data().set(key, amount);
+}
+
+fn accrue_to_child(env: &Env, token: &BytesN<32>, recipient: &Address, percentage: &u32, base_balance: &BigInt) -> BigInt {
+    let amount = get_child_amount(&base_balance, &percentage);
+
+    set_accrued(&env, &recipient, &token, &(get_accrued(&env, &recipient, &token) + &amount));
+    env.events().publish((symbol!("accrued"), recipient.clone(), token.clone()), amount.clone());
+
+    amount
 }
 
-fn set_token_contract_id(e: &Env, token_id: &BytesN<32>) {
-    e.data().set(DataKey::TkContract, token_id);
+fn take_accrued(env: &Env, recipient: &Address, token: &BytesN<32>) -> BigInt {
+    check_not_paused(&env);
+
+    let amount = get_accrued(&env, &recipient, &token);
+
+    if amount == BigInt::zero(&env) {
+        panic!("The recipient doesn't have accrued donations");
+    }
+
+    env.data().remove(DataKey::Accrued(AccruedKey { recipient: recipient.clone(), token: token.clone() }));
+
+    amount
 }
 
-fn donate_to_child(env: &Env, child_address: &Identifier, percentage: &u32, base_balance: &BigInt) {
-    let tc_id = get_token_contract_id(&env);
-    let client = token::Client::new(&env, &tc_id);
+fn withdraw_accrued(env: &Env, recipient: &Address, token: &BytesN<32>) {
+    let account_id = match recipient {
+        Address::Account(account_id) => account_id.clone(),
+        Address::Contract(_) => panic!("Contract recipients must be poked to keep the donation cascading"),
+    };
 
-    let amount: BigInt = (base_balance * percentage) / 100;
+    let amount = take_accrued(&env, &recipient, &token);
+    donate_to_child(&env, &token, &Identifier::Account(account_id), &100, &amount);
+}
+
+fn poke_child(env: &Env, recipient: &Address, token: &BytesN<32>) {
+    let contract_id = match recipient {
+        Address::Contract(contract_id) => contract_id.clone(),
+        Address::Account(_) => panic!("Only contract recipients can be poked"),
+    };
+
+    let amount = take_accrued(&env, &recipient, &token);
+
+    let parents: Vec<Address> = vec![
+        &env,
+        Address::Contract(env.current_contract())
+    ];
+
+    donate_to_parent_child(&env, &token, &contract_id, &100, &amount, &parents);
+}
+
+// Every child receives the same rounded down share, whether it's paid or accrued.
+fn get_child_amount(base_balance: &BigInt, percentage: &u32) -> BigInt {
+    (base_balance * percentage) / 100
+}
+
+fn donate_to_child(env: &Env, token: &BytesN<32>, child_address: &Identifier, percentage: &u32, base_balance: &BigInt) -> BigInt {
+    let client = token::Client::new(&env, &token);
+
+    let amount: BigInt = get_child_amount(&base_balance, &percentage);
     
     client.xfer(
         &Signature::Invoker,
@@ -56,44 +570,231 @@ fn donate_to_child(env: &Env, child_address: &Identifier, percentage: &u32, base
         &child_address,
         &amount
     );
+
+    amount
 }
 
-fn donate_to_parent_child(env: &Env, recipient_contract_id: &BytesN<32>, percentage: &u32, base_balance: &BigInt, parents: &Vec<Address>) {
-    donate_to_child(&env, &Identifier::Contract(recipient_contract_id.clone()), &percentage, &base_balance);
+fn donate_to_parent_child(env: &Env, token: &BytesN<32>, recipient_contract_id: &BytesN<32>, percentage: &u32, base_balance: &BigInt, parents: &Vec<Address>) -> BigInt {
+    let amount = donate_to_child(&env, &token, &Identifier::Contract(recipient_contract_id.clone()), &percentage, &base_balance);
     
     let args: Vec<RawVal> = vec![
         &env,
-        parents.to_raw()
+        parents.to_raw(),
+        token.clone().into_val(&env),
+        amount.clone().into_val(&env)
     ];
 
-    env.invoke_contract(&recipient_contract_id, &symbol!("donate_ch"), args)
+    env.invoke_contract::<()>(&recipient_contract_id, &symbol!("donate_ch"), args);
+
+    amount
 }
 
-fn apply_donation_type(env: &Env, child: &Recipient, base_balance: &BigInt, parents: &Vec<Address>) {
-    let destination = child.dest.clone();
+fn apply_donation_type(env: &Env, token: &BytesN<32>, child: &Recipient, base_balance: &BigInt, parents: &Vec<Address>) -> BigInt {
+    check_not_circular(&parents, &child);
 
-    if parents.contains(destination) {
-        panic!("Circular cascading aren't allowed, verify the children from {:?}", child.name);
+    if !accepts_token(&child, &token) {
+        return BigInt::zero(&env);
+    }
+
+    if is_pull_mode(&env) {
+        return accrue_to_child(&env, &token, &child.dest, &child.percentage, &base_balance);
     }
 
     match &child.dest {
-        Address::Contract(contract_id) => donate_to_parent_child(&env, &contract_id, &child.percentage, &base_balance, &parents),
-        Address::Account(account_id) =>  donate_to_child(&env, &Identifier::Account(account_id.clone()), &child.percentage, &base_balance),
+        Address::Contract(contract_id) => donate_to_parent_child(&env, &token, &contract_id, &child.percentage, &base_balance, &parents),
+        Address::Account(account_id) =>  donate_to_child(&env, &token, &Identifier::Account(account_id.clone()), &child.percentage, &base_balance),
     }
 }
 
-fn apply_children_donations(env: &Env, base_balance: &BigInt, parents: &Vec<Address>) {
+// Splits the donated amount among the children, whatever isn't sent to them is retained.
+fn apply_children_donations(env: &Env, token: &BytesN<32>, amount: &BigInt, parents: &Vec<Address>) {
+    let mut distributed = BigInt::zero(&env);
+
     for child in get_children(&env) {
         match child {
-            Ok(recipient) => apply_donation_type(env, &recipient, &base_balance, &parents),
+            Ok(recipient) => distributed = distributed + apply_donation_type(env, &token, &recipient, &amount, &parents),
             Err(error) => panic!("Problem reading the recipient: {:?}", error),
         }
     }
+
+    set_retained(&env, &token, &(get_retained(&env, &token) + (amount - distributed)));
+}
+
+// PREVIEW
+// Follows the same checks and rounding as the donations without moving any funds, so a preview
+// fails wherever the donation would fail, except that previews stay available while paused.
+fn preview_children(env: &Env, token: &BytesN<32>, amount: &BigInt, parents: &Vec<Address>) -> Vec<Payout> {
+    let mut payouts: Vec<Payout> = vec![&env];
+    let parent = parents.last().unwrap().unwrap();
+
+    for child in get_children(&env).iter() {
+        let recipient = child.unwrap();
+
+        check_not_circular(&parents, &recipient);
+
+        if !accepts_token(&recipient, &token) {
+            continue;
+        }
+
+        let child_amount = get_child_amount(&amount, &recipient.percentage);
+
+        payouts.push_back(Payout {
+            name: recipient.name.clone(),
+            dest: recipient.dest.clone(),
+            parent: parent.clone(),
+            amount: child_amount.clone(),
+            depth: parents.len()
+        });
+
+        // Pull mode children only cascade further once they're poked
+        match &recipient.dest {
+            Address::Contract(contract_id) if !is_pull_mode(&env) => {
+                let args: Vec<RawVal> = vec![
+                    &env,
+                    parents.to_raw(),
+                    token.clone().into_val(&env),
+                    child_amount.into_val(&env)
+                ];
+
+                let nested: Vec<Payout> = env.invoke_contract(&contract_id, &symbol!("preview_ch"), args);
+                payouts.append(&nested);
+            },
+            _ => {},
+        }
+    }
+
+    payouts
+}
+
+fn preview_main_donation(env: &Env, token: &BytesN<32>, amount: &BigInt) -> Vec<Payout> {
+    check_token(&env, &token);
+
+    let parents: Vec<Address> = vec![
+        &env,
+        Address::Contract(env.current_contract())
+    ];
+
+    preview_children(&env, &token, &amount, &parents)
+}
+
+// TREE
+// The path ends with this cascade, the children's shares are the parent's share times their percentage.
+fn get_tree(env: &Env, path: &Vec<Address>, share: u32) -> Vec<Node> {
+    check_walk_depth(&path);
+
+    let mut nodes: Vec<Node> = vec![&env];
+    let parent = path.last().unwrap().unwrap();
+
+    for child in get_children(&env).iter() {
+        let recipient = child.unwrap();
+
+        check_not_circular(&path, &recipient);
+
+        let child_share = (share as u64 * recipient.percentage as u64 / 100) as u32;
+
+        nodes.push_back(Node {
+            name: recipient.name.clone(),
+            dest: recipient.dest.clone(),
+            parent: parent.clone(),
+            percentage: recipient.percentage,
+            share: child_share,
+            depth: path.len()
+        });
+
+        if let Address::Contract(contract_id) = &recipient.dest {
+            let mut child_path = path.clone();
+            child_path.push_back(recipient.dest.clone());
+
+            let args: Vec<RawVal> = vec![
+                &env,
+                child_path.to_raw(),
+                child_share.into_val(&env)
+            ];
+
+            let nested: Vec<Node> = env.invoke_contract(&contract_id, &symbol!("g_tree_ch"), args);
+            nodes.append(&nested);
+        }
+    }
+
+    nodes
+}
+
+// RECEIPTS
+fn get_receipt(env: &Env, id: u32) -> Receipt {
+    let key = DataKey::Receipt(id);
+
+    match env.data().get(key) {
+        Some(receipt) => receipt.unwrap(),
+        None => panic!("The receipt doesn't exist"),
+    }
+}
+
+fn get_donor_receipts(env: &Env, donor: &Identifier) -> Vec<u32> {
+    let key = DataKey::DonorRcpts(donor.clone());
+    env.data()
+        .get(key)
+        .unwrap_or(Ok(vec![&env]))
+        .unwrap()
+}
+
+fn get_donor_total(env: &Env, donor: &Identifier, token: &BytesN<32>) -> BigInt {
+    let key = DataKey::DonorTotal(DonorKey { donor: donor.clone(), token: token.clone() });
+    env.data()
+        .get(key)
+        .unwrap_or(Ok(BigInt::zero(&env)))
+        .unwrap()
+}
+
+fn get_raised(env: &Env, token: &BytesN<32>) -> BigInt {
+    let key = DataKey::Raised(token.clone());
+    env.data()
+        .get(key)
+        .unwrap_or(Ok(BigInt::zero(&env)))
+        .unwrap()
+}
+
+fn record_donation(env: &Env, token: &BytesN<32>, donor: &Identifier, amount: &BigInt) -> u32 {
+    let id: u32 = env.data().get(DataKey::ReceiptId).unwrap_or(Ok(0)).unwrap();
+
+    env.data().set(DataKey::Receipt(id), Receipt {
+        donor: donor.clone(),
+        token: token.clone(),
+        amount: amount.clone(),
+        time: env.ledger().timestamp(),
+        children_v: get_children_version(&env)
+    });
+    env.data().set(DataKey::ReceiptId, id + 1);
+
+    let mut receipts = get_donor_receipts(&env, &donor);
+    receipts.push_back(id);
+    env.data().set(DataKey::DonorRcpts(donor.clone()), receipts);
+
+    let total = get_donor_total(&env, &donor, &token) + amount;
+    env.data().set(DataKey::DonorTotal(DonorKey { donor: donor.clone(), token: token.clone() }), total);
+    env.data().set(DataKey::Raised(token.clone()), get_raised(&env, &token) + amount);
+
+    env.events().publish((symbol!("donation"), donor.clone(), token.clone()), (id, amount.clone()));
+
+    id
+}
+
+fn get_donor_history(env: &Env, donor: &Identifier, start: u32, limit: u32) -> Vec<Receipt> {
+    let receipts = get_donor_receipts(&env, &donor);
+    let end = core::cmp::min(start.saturating_add(limit), receipts.len());
+
+    let mut history: Vec<Receipt> = vec![&env];
+    for index in start..end {
+        history.push_back(get_receipt(&env, receipts.get(index).unwrap().unwrap()));
+    }
+
+    history
 }
 
-fn apply_main_donation(env: &Env, donor: &Identifier, amount: &BigInt) {
-    let tc_id = get_token_contract_id(&env);
-    let client = token::Client::new(&env, &tc_id);
+fn apply_main_donation(env: &Env, token: &BytesN<32>, donor: &Identifier, amount: &BigInt) -> u32 {
+    check_not_paused(&env);
+    check_token(&env, &token);
+
+    let client = token::Client::new(&env, &token);
 
     let contract = env.current_contract();
     let contract_identifier = Identifier::Contract(contract.clone());
@@ -106,7 +807,6 @@ fn apply_main_donation(env: &Env, donor: &Identifier, amount: &BigInt) {
         &amount
     );
 
-    let contract_balance = client.balance(&contract_identifier);
     let contract_address = Address::Contract(contract);
 
     let parents: Vec<Address> = vec![
@@ -114,53 +814,266 @@ fn apply_main_donation(env: &Env, donor: &Identifier, amount: &BigInt) {
         contract_address
     ];
 
-    apply_children_donations(&env, &contract_balance, &parents);
+    apply_children_donations(&env, &token, &amount, &parents);
+
+    record_donation(&env, &token, &donor, &amount)
 }
 pub struct CascadingDonationContract;
 
 pub trait CascadingDonationContractTrait {
-    fn initialize(env: Env, tc_id: BytesN<32>, children: Vec<Recipient>);
-    fn donate(env: Env, amount: BigInt, donor: Identifier);
-    fn donate_ch(env: Env, parents: Vec<Address>);
-    fn s_children(env: Env, new_children: Vec<Recipient>);
+    fn initialize(env: Env, tokens: Vec<BytesN<32>>, children: Vec<Recipient>, guardian: Identifier, owner: Identifier);
+    fn donate(env: Env, token: BytesN<32>, amount: BigInt, donor: Identifier) -> u32;
+    fn donate_ch(env: Env, parents: Vec<Address>, token: BytesN<32>, amount: BigInt);
+    fn s_children(env: Env, owner_sign: Signature, nonce: BigInt, new_children: Vec<Recipient>);
     fn g_children(env: Env) -> Vec<Recipient>;
+    fn nonce(env: Env, id: Identifier) -> BigInt;
+    fn pause(env: Env, guardian_sign: Signature, nonce: BigInt);
+    fn unpause(env: Env, guardian_sign: Signature, nonce: BigInt);
+    fn paused(env: Env) -> bool;
+    fn version(env: Env) -> u32;
+    fn owner(env: Env) -> Identifier;
+    fn s_owner(env: Env, owner_sign: Signature, nonce: BigInt, new_owner: Identifier);
+    fn retained(env: Env, token: BytesN<32>) -> BigInt;
+    fn w_retained(env: Env, owner_sign: Signature, nonce: BigInt, token: BytesN<32>, to: Identifier, amount: BigInt);
+    fn s_parents(env: Env, owner_sign: Signature, nonce: BigInt, parents: Vec<BytesN<32>>);
+    fn g_parents(env: Env) -> Vec<BytesN<32>>;
+    fn s_limits(env: Env, owner_sign: Signature, nonce: BigInt, limits: Limits);
+    fn limits(env: Env) -> Limits;
+    fn held(env: Env, token: BytesN<32>) -> BigInt;
+    fn flush(env: Env, token: BytesN<32>);
+    fn s_pull(env: Env, owner_sign: Signature, nonce: BigInt, enabled: bool);
+    fn pull_mode(env: Env) -> bool;
+    fn pending(env: Env, recipient: Address, token: BytesN<32>) -> BigInt;
+    fn withdraw(env: Env, recipient: Address, token: BytesN<32>);
+    fn poke(env: Env, recipient: Address, token: BytesN<32>);
+    fn s_tokens(env: Env, owner_sign: Signature, nonce: BigInt, tokens: Vec<BytesN<32>>);
+    fn tokens(env: Env) -> Vec<BytesN<32>>;
+    fn preview(env: Env, token: BytesN<32>, amount: BigInt) -> Vec<Payout>;
+    fn preview_ch(env: Env, parents: Vec<Address>, token: BytesN<32>, amount: BigInt) -> Vec<Payout>;
+    fn g_tree(env: Env) -> Vec<Node>;
+    fn g_tree_ch(env: Env, path: Vec<Address>, share: u32) -> Vec<Node>;
+    fn receipt(env: Env, id: u32) -> Receipt;
+    fn history(env: Env, donor: Identifier, start: u32, limit: u32) -> Vec<Receipt>;
+    fn donor_tot(env: Env, donor: Identifier, token: BytesN<32>) -> BigInt;
+    fn raised(env: Env, token: BytesN<32>) -> BigInt;
+    fn children_v(env: Env) -> u32;
 }
 
 #[contractimpl]
 impl CascadingDonationContractTrait for CascadingDonationContract {
-    fn initialize(env: Env, tc_id: BytesN<32>, children: Vec<Recipient>) {
-        set_token_contract_id(&env, &tc_id);
-        set_children(&env, &children)
+    fn initialize(env: Env, tokens: Vec<BytesN<32>>, children: Vec<Recipient>, guardian: Identifier, owner: Identifier) {
+        check_not_initialized(&env);
+        check_fanout(&env, &children);
+        check_percentages(&children);
+        check_cycles(&env, &children);
+
+        set_tokens(&env, &tokens);
+        set_children(&env, &resolve_child_tokens(&env, &children));
+        set_guardian(&env, &guardian);
+        set_owner(&env, &owner);
+        set_schema_version(&env, SCHEMA_VERSION)
     }
 
-    fn donate(env: Env, amount: BigInt, donor: Identifier) {
-        apply_main_donation(&env, &donor, &amount);
+    fn donate(env: Env, token: BytesN<32>, amount: BigInt, donor: Identifier) -> u32 {
+        apply_main_donation(&env, &token, &donor, &amount)
     }
 
-    fn donate_ch(env: Env, parents: Vec<Address>) {
-        let tc_id = get_token_contract_id(&env);
-        let client = token::Client::new(&env, &tc_id);
+    fn donate_ch(env: Env, parents: Vec<Address>, token: BytesN<32>, amount: BigInt) {
+        check_not_paused(&env);
+        check_parent(&env, &parents);
+        check_token(&env, &token);
 
-        let contract = env.current_contract();
-        let contract_identifier = Identifier::Contract(contract.clone());
+        if parents.len() >= get_limits(&env).depth {
+            hold_donation(&env, &token, &amount);
+            return;
+        }
 
-        let contract_balance = client.balance(&contract_identifier);
-        let contract_address = Address::Contract(contract);
+        let contract_address = Address::Contract(env.current_contract());
 
         let mut updated_parent: Vec<Address> = parents.clone();
 
         updated_parent.push_back(contract_address);
 
-        apply_children_donations(&env, &contract_balance, &updated_parent);
+        apply_children_donations(&env, &token, &amount, &updated_parent);
     }
 
-    fn s_children(env: Env, new_children: Vec<Recipient>) {
-        set_children(&env, &new_children);
+    fn s_children(env: Env, owner_sign: Signature, nonce: BigInt, new_children: Vec<Recipient>) {
+        check_owner(&env, &owner_sign, &nonce);
+        verify(&env, &owner_sign, symbol!("s_children"), (owner_sign.identifier(&env), nonce, new_children.clone()));
+
+        check_fanout(&env, &new_children);
+        check_percentages(&new_children);
+        check_cycles(&env, &new_children);
+        set_children(&env, &resolve_child_tokens(&env, &new_children));
     }
 
     fn g_children(env: Env) -> Vec<Recipient> {
         get_children(&env)
     }
+
+    fn nonce(env: Env, id: Identifier) -> BigInt {
+        read_nonce(&env, &id)
+    }
+
+    fn pause(env: Env, guardian_sign: Signature, nonce: BigInt) {
+        check_guardian(&env, &guardian_sign, &nonce);
+        verify(&env, &guardian_sign, symbol!("pause"), (guardian_sign.identifier(&env), nonce));
+
+        set_paused(&env, true);
+    }
+
+    fn unpause(env: Env, guardian_sign: Signature, nonce: BigInt) {
+        check_guardian(&env, &guardian_sign, &nonce);
+        verify(&env, &guardian_sign, symbol!("unpause"), (guardian_sign.identifier(&env), nonce));
+
+        set_paused(&env, false);
+    }
+
+    fn paused(env: Env) -> bool {
+        is_paused(&env)
+    }
+
+    fn version(env: Env) -> u32 {
+        get_schema_version(&env)
+    }
+
+    fn owner(env: Env) -> Identifier {
+        get_owner(&env)
+    }
+
+    fn s_owner(env: Env, owner_sign: Signature, nonce: BigInt, new_owner: Identifier) {
+        check_owner(&env, &owner_sign, &nonce);
+        verify(&env, &owner_sign, symbol!("s_owner"), (owner_sign.identifier(&env), nonce, new_owner.clone()));
+
+        transfer_ownership(&env, &new_owner);
+    }
+
+    fn retained(env: Env, token: BytesN<32>) -> BigInt {
+        get_retained(&env, &token)
+    }
+
+    fn w_retained(env: Env, owner_sign: Signature, nonce: BigInt, token: BytesN<32>, to: Identifier, amount: BigInt) {
+        check_owner(&env, &owner_sign, &nonce);
+        verify(&env, &owner_sign, symbol!("w_retained"), (owner_sign.identifier(&env), nonce, token.clone(), to.clone(), amount.clone()));
+
+        withdraw_retained(&env, &token, &to, &amount);
+    }
+
+    fn s_parents(env: Env, owner_sign: Signature, nonce: BigInt, parents: Vec<BytesN<32>>) {
+        check_owner(&env, &owner_sign, &nonce);
+        verify(&env, &owner_sign, symbol!("s_parents"), (owner_sign.identifier(&env), nonce, parents.clone()));
+
+        set_parents(&env, &parents);
+    }
+
+    fn g_parents(env: Env) -> Vec<BytesN<32>> {
+        get_parents(&env)
+    }
+
+    fn s_limits(env: Env, owner_sign: Signature, nonce: BigInt, limits: Limits) {
+        check_owner(&env, &owner_sign, &nonce);
+        verify(&env, &owner_sign, symbol!("s_limits"), (owner_sign.identifier(&env), nonce, limits.clone()));
+
+        set_limits(&env, &limits);
+    }
+
+    fn limits(env: Env) -> Limits {
+        get_limits(&env)
+    }
+
+    fn held(env: Env, token: BytesN<32>) -> BigInt {
+        get_held(&env, &token)
+    }
+
+    fn flush(env: Env, token: BytesN<32>) {
+        flush_held(&env, &token);
+    }
+
+    fn s_pull(env: Env, owner_sign: Signature, nonce: BigInt, enabled: bool) {
+        check_owner(&env, &owner_sign, &nonce);
+        verify(&env, &owner_sign, symbol!("s_pull"), (owner_sign.identifier(&env), nonce, enabled));
+
+        env.data().set(DataKey::PullMode, enabled);
+    }
+
+    fn pull_mode(env: Env) -> bool {
+        is_pull_mode(&env)
+    }
+
+    fn pending(env: Env, recipient: Address, token: BytesN<32>) -> BigInt {
+        get_accrued(&env, &recipient, &token)
+    }
+
+    fn withdraw(env: Env, recipient: Address, token: BytesN<32>) {
+        withdraw_accrued(&env, &recipient, &token);
+    }
+
+    fn poke(env: Env, recipient: Address, token: BytesN<32>) {
+        poke_child(&env, &recipient, &token);
+    }
+
+    fn s_tokens(env: Env, owner_sign: Signature, nonce: BigInt, tokens: Vec<BytesN<32>>) {
+        check_owner(&env, &owner_sign, &nonce);
+        verify(&env, &owner_sign, symbol!("s_tokens"), (owner_sign.identifier(&env), nonce, tokens.clone()));
+
+        set_tokens(&env, &tokens);
+    }
+
+    fn tokens(env: Env) -> Vec<BytesN<32>> {
+        get_tokens(&env)
+    }
+
+    fn preview(env: Env, token: BytesN<32>, amount: BigInt) -> Vec<Payout> {
+        preview_main_donation(&env, &token, &amount)
+    }
+
+    fn preview_ch(env: Env, parents: Vec<Address>, token: BytesN<32>, amount: BigInt) -> Vec<Payout> {
+        check_parent(&env, &parents);
+        check_token(&env, &token);
+
+        // Donations reaching the depth limit are held, so they don't reach the children yet
+        if parents.len() >= get_limits(&env).depth {
+            return vec![&env];
+        }
+
+        let mut updated_parent: Vec<Address> = parents.clone();
+        updated_parent.push_back(Address::Contract(env.current_contract()));
+
+        preview_children(&env, &token, &amount, &updated_parent)
+    }
+
+    fn g_tree(env: Env) -> Vec<Node> {
+        let path: Vec<Address> = vec![
+            &env,
+            Address::Contract(env.current_contract())
+        ];
+
+        get_tree(&env, &path, FULL_SHARE)
+    }
+
+    fn g_tree_ch(env: Env, path: Vec<Address>, share: u32) -> Vec<Node> {
+        get_tree(&env, &path, share)
+    }
+
+    fn receipt(env: Env, id: u32) -> Receipt {
+        get_receipt(&env, id)
+    }
+
+    fn history(env: Env, donor: Identifier, start: u32, limit: u32) -> Vec<Receipt> {
+        get_donor_history(&env, &donor, start, limit)
+    }
+
+    fn donor_tot(env: Env, donor: Identifier, token: BytesN<32>) -> BigInt {
+        get_donor_total(&env, &donor, &token)
+    }
+
+    fn raised(env: Env, token: BytesN<32>) -> BigInt {
+        get_raised(&env, &token)
+    }
+
+    fn children_v(env: Env) -> u32 {
+        get_children_version(&env)
+    }
 }
 
 #[cfg(test)]
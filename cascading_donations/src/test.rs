@@ -1,6 +1,6 @@
 #![cfg(test)]
 
-use super::{CascadingDonationContract, CascadingDonationContractClient, Identifier, Recipient};
+use super::{CascadingDonationContract, CascadingDonationContractClient, Identifier, Limits, Node, Payout, Recipient};
 use soroban_sdk::{symbol, vec, Env, testutils::{Accounts}, BigInt, IntoVal, BytesN, Vec};
 use soroban_auth::{Signature};
 
@@ -73,21 +73,23 @@ fn basic_donation_without_cascade() {
     Recipient {
             dest: soroban_sdk::Address::Account(dependency_1.clone()),
             name: symbol!("dep_1"),
-            percentage: 10
+            percentage: 10,
+            tokens: vec![&env]
         };
 
     let child_2 =
     Recipient {
             dest: soroban_sdk::Address::Account(dependency_2.clone()),
             name: symbol!("dep_2"),
-            percentage: 30
+            percentage: 30,
+            tokens: vec![&env]
         };
 
     let mut children: Vec<Recipient> = vec![&env];
     children.push_back(child_1);
     children.push_back(child_2);
 
-    contract_client.initialize(&token_id, &children);
+    contract_client.initialize(&vec![&env, token_id.clone()], &children, &admin_id, &admin_id);
 
     // FUND DONOR ACCOUNT
     token_client.with_source_account(&admin).mint(
@@ -111,7 +113,7 @@ fn basic_donation_without_cascade() {
     std::println!("======= dependency_2 BALANCE ========: {:?}", token_client.balance(&dependency_2_id));
     std::println!("==================================");
 
-    contract_client.with_source_account(&donor).donate(&BigInt::from_u32(&env, 1000), &donor_id);
+    contract_client.with_source_account(&donor).donate(&token_id, &BigInt::from_u32(&env, 1000), &donor_id);
 
     assert_eq!(
         token_client.balance(&Identifier::Contract(contract_id.clone())),
@@ -198,21 +200,24 @@ fn contract_with_parent_children() {
         Recipient {
             dest: soroban_sdk::Address::Account(sub_dependency_1.clone()),
             name: symbol!("subdep_1"),
-            percentage: 20
+            percentage: 20,
+            tokens: vec![&env]
         };
 
     let parent_1_child_2 =
         Recipient {
             dest: soroban_sdk::Address::Account(sub_dependency_2.clone()),
             name: symbol!("subdep_2"),
-            percentage: 20
+            percentage: 20,
+            tokens: vec![&env]
         };
 
     parent1_children.push_back(parent_1_child_1);
     parent1_children.push_back(parent_1_child_2);
     // END CHILD PARENT CHILDREN
 
-    child_contract_client.initialize(&token_id, &parent1_children);
+    child_contract_client.initialize(&vec![&env, token_id.clone()], &parent1_children, &admin_id, &admin_id);
+    child_contract_client.with_source_account(&admin).s_parents(&Signature::Invoker, &BigInt::zero(&env), &vec![&env, contract_id.clone()]);
     std::println!("======= CHILD CONTRACT CHILDREN ========: {:?}", child_contract_client.g_children());
     std::println!("========================================:");
 
@@ -221,14 +226,16 @@ fn contract_with_parent_children() {
         Recipient {
             dest: soroban_sdk::Address::Contract(child_contract_id.clone()),
             name: symbol!("c_parent_1"),
-            percentage: 20
+            percentage: 20,
+            tokens: vec![&env]
         };
 
     let child_1 =
         Recipient {
             dest: soroban_sdk::Address::Account(dependency_1.clone()),
             name: symbol!("dep_1"),
-            percentage: 20
+            percentage: 20,
+            tokens: vec![&env]
         };
     // END CHILDREN
 
@@ -236,7 +243,7 @@ fn contract_with_parent_children() {
     children.push_back(child_1);
     children.push_back(child_parent_1);
 
-    contract_client.initialize(&token_id, &children);
+    contract_client.initialize(&vec![&env, token_id.clone()], &children, &admin_id, &admin_id);
     std::println!("======= MAIN CONTRACT CHILDREN ========: {:?}", contract_client.g_children());
 
     // FUND DONOR ACCOUNT
@@ -263,7 +270,7 @@ fn contract_with_parent_children() {
         &BigInt::from_u32(&env, 1000)
     );
 
-    contract_client.with_source_account(&donor).donate(&BigInt::from_u32(&env, 1000), &donor_id);
+    contract_client.with_source_account(&donor).donate(&token_id, &BigInt::from_u32(&env, 1000), &donor_id);
 
     assert_eq!(
         token_client.balance(&Identifier::Contract(contract_id.clone())),
@@ -308,12 +315,8 @@ fn contract_with_parent_children_and_circular_schema() {
     |--     sub_dependency_2
 
     Expected workflow
-    1. Donate 1000 to MAIN PROJECT
-    2. The MAIN PROJECT receives the donation
-    3. Auto donate to dependency_1 with a xfer
-    4. Auto donate to dependency_2 with a donation invocation (Should be a contract since is a child with sub childs)
-    5. The dependency_2 receives the donation
-    6. Auto donate to sub_dependency_1 fails because it points to a parent recipient.
+    1. Initialize dependency_2 (a contract since it has sub childs)
+    2. Initializing dependency_2 fails because sub_dependency_1 points to dependency_2 itself.
 */
 
 let env = Env::default();
@@ -322,16 +325,6 @@ let env = Env::default();
 let admin = env.accounts().generate();
 let admin_id = Identifier::Account(admin.clone());
 
-let donor = env.accounts().generate();
-let donor_id = Identifier::Account(donor.clone());
-
-// PARENT CONTRACT (PARENT PROJECT)
-let contract_id = env.register_contract(None, CascadingDonationContract);
-let contract_client = CascadingDonationContractClient::new(&env, &contract_id);
-
-// PARENT PROJECT CHILDREN ACCOUNTS
-let dependency_1 = env.accounts().generate();
-
 // PARENT CHILD CONTRACT (CHILD CONTRACT)
 let child_contract_id = env.register_contract(None, CascadingDonationContract);
 let child_contract_client = CascadingDonationContractClient::new(&env, &child_contract_id);
@@ -340,67 +333,1132 @@ let child_contract_client = CascadingDonationContractClient::new(&env, &child_co
 let sub_dependency_2 = env.accounts().generate();
 
 // CREATE TOKEN CONTRACT
-let (token_id, token_client) = create_and_init_token_contract(&env, &admin_id);
+let (token_id, _token_client) = create_and_init_token_contract(&env, &admin_id);
 
 // CHILD PARENT CHILDREN
 let mut parent1_children: Vec<Recipient> = vec![&env];
 let parent_1_child_1 =
     Recipient {
-        dest: soroban_sdk::Address::Contract(child_contract_id.clone()), // Here one of the child recipients pints to the main one (main contract).
+        dest: soroban_sdk::Address::Contract(child_contract_id.clone()), // Here one of the child recipients points to the child contract itself.
         name: symbol!("subdep_1"),
-        percentage: 20
+        percentage: 20,
+        tokens: vec![&env]
     };
 
 let parent_1_child_2 =
     Recipient {
         dest: soroban_sdk::Address::Account(sub_dependency_2.clone()),
         name: symbol!("subdep_2"),
-        percentage: 20
+        percentage: 20,
+        tokens: vec![&env]
     };
 
 parent1_children.push_back(parent_1_child_1);
 parent1_children.push_back(parent_1_child_2);
 // END CHILD PARENT CHILDREN
 
-child_contract_client.initialize(&token_id, &parent1_children);
+child_contract_client.initialize(&vec![&env, token_id.clone()], &parent1_children, &admin_id, &admin_id);
+}
 
-//PARENT CHILDREN
-let child_parent_1 =
-    Recipient {
-        dest: soroban_sdk::Address::Contract(child_contract_id.clone()),
-        name: symbol!("c_parent_1"),
-        percentage: 20
-    };
 
-let child_1 =
-    Recipient {
-        dest: soroban_sdk::Address::Account(dependency_1.clone()),
-        name: symbol!("dep_1"),
-        percentage: 20
-    };
-// END CHILDREN
-
-let mut children: Vec<Recipient> = vec![&env];
-children.push_back(child_1);
-children.push_back(child_parent_1);
-
-contract_client.initialize(&token_id, &children);
-
-// FUND DONOR ACCOUNT
-token_client.with_source_account(&admin).mint(
-    &Signature::Invoker,
-    &BigInt::zero(&env),
-    &donor_id,
-    &BigInt::from_u32(&env, 2000)
-);
-
-token_client.with_source_account(&donor).approve(
-    &Signature::Invoker,
-    &BigInt::zero(&env),
-    &Identifier::Contract(contract_id.clone()),
-    &BigInt::from_u32(&env, 1000)
-);
-
-contract_client.with_source_account(&donor).donate(&BigInt::from_u32(&env, 1000), &donor_id);
+#[test]
+#[should_panic(expected = "The cascade is paused")]
+fn donate_while_paused() {
+    let env = Env::default();
+
+    let admin = env.accounts().generate();
+    let admin_id = Identifier::Account(admin.clone());
+
+    let donor = env.accounts().generate();
+    let donor_id = Identifier::Account(donor.clone());
+
+    let contract_id = env.register_contract(None, CascadingDonationContract);
+    let contract_client = CascadingDonationContractClient::new(&env, &contract_id);
+
+    let (token_id, _token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    let dependency_1 = env.accounts().generate();
+    let children: Vec<Recipient> = vec![
+        &env,
+        Recipient {
+            dest: soroban_sdk::Address::Account(dependency_1.clone()),
+            name: symbol!("dep_1"),
+            percentage: 10,
+            tokens: vec![&env]
+        }
+    ];
+
+    contract_client.initialize(&vec![&env, token_id.clone()], &children, &admin_id, &admin_id);
+    contract_client.with_source_account(&admin).pause(&Signature::Invoker, &BigInt::zero(&env));
+
+    assert!(contract_client.paused(), "Cascade is paused");
+    assert_eq!(contract_client.g_children().len(), 1, "Children can be queried while paused");
+
+    contract_client.with_source_account(&donor).donate(&token_id, &BigInt::from_u32(&env, 1000), &donor_id);
+}
+
+#[test]
+#[should_panic(expected = "The cascade is paused")]
+fn donate_child_while_paused() {
+    let env = Env::default();
+
+    let admin = env.accounts().generate();
+    let admin_id = Identifier::Account(admin.clone());
+
+    let child_contract_id = env.register_contract(None, CascadingDonationContract);
+    let child_contract_client = CascadingDonationContractClient::new(&env, &child_contract_id);
+
+    let (token_id, _token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    let sub_dependency_1 = env.accounts().generate();
+    let children: Vec<Recipient> = vec![
+        &env,
+        Recipient {
+            dest: soroban_sdk::Address::Account(sub_dependency_1.clone()),
+            name: symbol!("subdep_1"),
+            percentage: 20,
+            tokens: vec![&env]
+        }
+    ];
+
+    child_contract_client.initialize(&vec![&env, token_id.clone()], &children, &admin_id, &admin_id);
+    child_contract_client.with_source_account(&admin).pause(&Signature::Invoker, &BigInt::zero(&env));
+
+    child_contract_client.donate_ch(&vec![&env], &token_id, &BigInt::from_u32(&env, 100));
+}
+
+#[test]
+#[should_panic(expected = "Only the guardian can perform this action")]
+fn stranger_cannot_pause() {
+    let env = Env::default();
+
+    let admin = env.accounts().generate();
+    let admin_id = Identifier::Account(admin.clone());
+
+    let stranger = env.accounts().generate();
+
+    let contract_id = env.register_contract(None, CascadingDonationContract);
+    let contract_client = CascadingDonationContractClient::new(&env, &contract_id);
+
+    let (token_id, _token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    contract_client.initialize(&vec![&env, token_id.clone()], &vec![&env], &admin_id, &admin_id);
+    contract_client.with_source_account(&stranger).pause(&Signature::Invoker, &BigInt::zero(&env));
+}
+
+#[test]
+#[should_panic(expected = "Only the owner can perform this action")]
+fn stranger_cannot_set_children() {
+    let env = Env::default();
+
+    let admin = env.accounts().generate();
+    let admin_id = Identifier::Account(admin.clone());
+
+    let stranger = env.accounts().generate();
+
+    let contract_id = env.register_contract(None, CascadingDonationContract);
+    let contract_client = CascadingDonationContractClient::new(&env, &contract_id);
+
+    let (token_id, _token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    contract_client.initialize(&vec![&env, token_id.clone()], &vec![&env], &admin_id, &admin_id);
+
+    let children: Vec<Recipient> = vec![
+        &env,
+        Recipient {
+            dest: soroban_sdk::Address::Account(stranger.clone()),
+            name: symbol!("stranger"),
+            percentage: 100,
+            tokens: vec![&env]
+        }
+    ];
+
+    contract_client.with_source_account(&stranger).s_children(&Signature::Invoker, &BigInt::zero(&env), &children);
+}
+
+#[test]
+fn transfer_ownership() {
+    let env = Env::default();
+
+    let admin = env.accounts().generate();
+    let admin_id = Identifier::Account(admin.clone());
+
+    let new_owner = env.accounts().generate();
+    let new_owner_id = Identifier::Account(new_owner.clone());
+
+    let dependency_1 = env.accounts().generate();
+
+    let contract_id = env.register_contract(None, CascadingDonationContract);
+    let contract_client = CascadingDonationContractClient::new(&env, &contract_id);
+
+    let (token_id, _token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    contract_client.initialize(&vec![&env, token_id.clone()], &vec![&env], &admin_id, &admin_id);
+    contract_client.with_source_account(&admin).s_owner(&Signature::Invoker, &BigInt::zero(&env), &new_owner_id);
+
+    assert_eq!(contract_client.owner(), new_owner_id, "Ownership was transferred");
+
+    let children: Vec<Recipient> = vec![
+        &env,
+        Recipient {
+            dest: soroban_sdk::Address::Account(dependency_1.clone()),
+            name: symbol!("dep_1"),
+            percentage: 10,
+            tokens: vec![&env]
+        }
+    ];
+
+    contract_client.with_source_account(&new_owner).s_children(&Signature::Invoker, &BigInt::zero(&env), &children);
+
+    assert_eq!(contract_client.g_children().len(), 1, "New owner sets the children");
+}
+
+#[test]
+#[should_panic(expected = "The cascade is already initialized")]
+fn stranger_cannot_initialize_again() {
+    let env = Env::default();
+
+    let admin = env.accounts().generate();
+    let admin_id = Identifier::Account(admin.clone());
+
+    let stranger = env.accounts().generate();
+    let stranger_id = Identifier::Account(stranger.clone());
+
+    let contract_id = env.register_contract(None, CascadingDonationContract);
+    let contract_client = CascadingDonationContractClient::new(&env, &contract_id);
+
+    let (token_id, _token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    contract_client.initialize(&vec![&env, token_id.clone()], &vec![&env], &admin_id, &admin_id);
+    contract_client.with_source_account(&stranger).initialize(&vec![&env, token_id.clone()], &vec![&env], &stranger_id, &stranger_id);
+}
+
+#[test]
+fn donation_splits_only_the_donated_amount() {
+    let env = Env::default();
+
+    let admin = env.accounts().generate();
+    let admin_id = Identifier::Account(admin.clone());
+
+    let donor = env.accounts().generate();
+    let donor_id = Identifier::Account(donor.clone());
+
+    let contract_id = env.register_contract(None, CascadingDonationContract);
+    let contract_client = CascadingDonationContractClient::new(&env, &contract_id);
+
+    let (token_id, token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    let dependency_1 = env.accounts().generate();
+    let dependency_1_id = Identifier::Account(dependency_1.clone());
+
+    let children: Vec<Recipient> = vec![
+        &env,
+        Recipient {
+            dest: soroban_sdk::Address::Account(dependency_1.clone()),
+            name: symbol!("dep_1"),
+            percentage: 40,
+            tokens: vec![&env]
+        }
+    ];
+
+    contract_client.initialize(&vec![&env, token_id.clone()], &children, &admin_id, &admin_id);
+
+    // Tokens sent to the contract outside of a donation
+    token_client.with_source_account(&admin).mint(
+        &Signature::Invoker,
+        &BigInt::zero(&env),
+        &Identifier::Contract(contract_id.clone()),
+        &BigInt::from_u32(&env, 500)
+    );
+
+    token_client.with_source_account(&admin).mint(
+        &Signature::Invoker,
+        &BigInt::zero(&env),
+        &donor_id,
+        &BigInt::from_u32(&env, 1000)
+    );
+
+    token_client.with_source_account(&donor).approve(
+        &Signature::Invoker,
+        &BigInt::zero(&env),
+        &Identifier::Contract(contract_id.clone()),
+        &BigInt::from_u32(&env, 1000)
+    );
+
+    contract_client.with_source_account(&donor).donate(&token_id, &BigInt::from_u32(&env, 1000), &donor_id);
+
+    assert_eq!(
+        token_client.balance(&dependency_1_id),
+        &BigInt::from_u32(&env, 400),
+        "Dependency 1 receives its share of the donation only"
+    );
+
+    assert_eq!(contract_client.retained(&token_id), BigInt::from_u32(&env, 600), "Retained share is tracked");
+
+    contract_client.with_source_account(&admin).w_retained(
+        &Signature::Invoker,
+        &BigInt::zero(&env),
+        &token_id,
+        &admin_id,
+        &BigInt::from_u32(&env, 600)
+    );
+
+    assert_eq!(contract_client.retained(&token_id), BigInt::zero(&env), "Retained share was withdrawn");
+    assert_eq!(token_client.balance(&admin_id), &BigInt::from_u32(&env, 600), "Owner receives the retained share");
+    assert_eq!(
+        token_client.balance(&Identifier::Contract(contract_id.clone())),
+        &BigInt::from_u32(&env, 500),
+        "Tokens outside of donations stay in the contract"
+    );
+}
+
+#[test]
+#[should_panic(expected = "The cascade is paused")]
+fn withdraw_retained_while_paused() {
+    let env = Env::default();
+
+    let admin = env.accounts().generate();
+    let admin_id = Identifier::Account(admin.clone());
+
+    let contract_id = env.register_contract(None, CascadingDonationContract);
+    let contract_client = CascadingDonationContractClient::new(&env, &contract_id);
+
+    let (token_id, _token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    contract_client.initialize(&vec![&env, token_id.clone()], &vec![&env], &admin_id, &admin_id);
+    contract_client.with_source_account(&admin).pause(&Signature::Invoker, &BigInt::zero(&env));
+
+    contract_client.with_source_account(&admin).w_retained(
+        &Signature::Invoker,
+        &BigInt::zero(&env),
+        &token_id,
+        &admin_id,
+        &BigInt::from_u32(&env, 100)
+    );
+}
+
+#[test]
+#[should_panic(expected = "The children percentages can't add up to more than 100")]
+fn set_children_over_full_percentage() {
+    let env = Env::default();
+
+    let admin = env.accounts().generate();
+    let admin_id = Identifier::Account(admin.clone());
+
+    let dependency_1 = env.accounts().generate();
+    let dependency_2 = env.accounts().generate();
+
+    let contract_id = env.register_contract(None, CascadingDonationContract);
+    let contract_client = CascadingDonationContractClient::new(&env, &contract_id);
+
+    let (token_id, _token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    contract_client.initialize(&vec![&env, token_id.clone()], &vec![&env], &admin_id, &admin_id);
+
+    let children: Vec<Recipient> = vec![
+        &env,
+        Recipient {
+            dest: soroban_sdk::Address::Account(dependency_1.clone()),
+            name: symbol!("dep_1"),
+            percentage: 60,
+            tokens: vec![&env]
+        },
+        Recipient {
+            dest: soroban_sdk::Address::Account(dependency_2.clone()),
+            name: symbol!("dep_2"),
+            percentage: 50,
+            tokens: vec![&env]
+        }
+    ];
+
+    contract_client.with_source_account(&admin).s_children(&Signature::Invoker, &BigInt::zero(&env), &children);
+}
+
+#[test]
+#[should_panic(expected = "Only registered parents can donate to the children")]
+fn stranger_cannot_donate_child() {
+    let env = Env::default();
+
+    let admin = env.accounts().generate();
+    let admin_id = Identifier::Account(admin.clone());
+
+    let stranger = env.accounts().generate();
+
+    let child_contract_id = env.register_contract(None, CascadingDonationContract);
+    let child_contract_client = CascadingDonationContractClient::new(&env, &child_contract_id);
+
+    let (token_id, _token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    let sub_dependency_1 = env.accounts().generate();
+    let children: Vec<Recipient> = vec![
+        &env,
+        Recipient {
+            dest: soroban_sdk::Address::Account(sub_dependency_1.clone()),
+            name: symbol!("subdep_1"),
+            percentage: 20,
+            tokens: vec![&env]
+        }
+    ];
+
+    child_contract_client.initialize(&vec![&env, token_id.clone()], &children, &admin_id, &admin_id);
+
+    child_contract_client.with_source_account(&stranger).donate_ch(&vec![&env], &token_id, &BigInt::from_u32(&env, 100));
 }
 
+#[test]
+#[should_panic(expected = "Circular cascading aren't allowed, verify the children from")]
+fn set_children_with_cycle() {
+    let env = Env::default();
+
+    let admin = env.accounts().generate();
+    let admin_id = Identifier::Account(admin.clone());
+
+    let contract_id = env.register_contract(None, CascadingDonationContract);
+    let contract_client = CascadingDonationContractClient::new(&env, &contract_id);
+
+    let child_contract_id = env.register_contract(None, CascadingDonationContract);
+    let child_contract_client = CascadingDonationContractClient::new(&env, &child_contract_id);
+
+    let (token_id, _token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    child_contract_client.initialize(&vec![&env, token_id.clone()], &vec![&env], &admin_id, &admin_id);
+
+    let children: Vec<Recipient> = vec![
+        &env,
+        Recipient {
+            dest: soroban_sdk::Address::Contract(child_contract_id.clone()),
+            name: symbol!("c_parent_1"),
+            percentage: 20,
+            tokens: vec![&env]
+        }
+    ];
+
+    contract_client.initialize(&vec![&env, token_id.clone()], &children, &admin_id, &admin_id);
+
+    // The child cascade can't point back to its parent
+    let child_children: Vec<Recipient> = vec![
+        &env,
+        Recipient {
+            dest: soroban_sdk::Address::Contract(contract_id.clone()),
+            name: symbol!("main"),
+            percentage: 20,
+            tokens: vec![&env]
+        }
+    ];
+
+    child_contract_client.with_source_account(&admin).s_children(&Signature::Invoker, &BigInt::zero(&env), &child_children);
+}
+
+#[test]
+fn hold_donations_at_depth_limit() {
+    let env = Env::default();
+
+    let admin = env.accounts().generate();
+    let admin_id = Identifier::Account(admin.clone());
+
+    let donor = env.accounts().generate();
+    let donor_id = Identifier::Account(donor.clone());
+
+    let contract_id = env.register_contract(None, CascadingDonationContract);
+    let contract_client = CascadingDonationContractClient::new(&env, &contract_id);
+
+    let child_contract_id = env.register_contract(None, CascadingDonationContract);
+    let child_contract_client = CascadingDonationContractClient::new(&env, &child_contract_id);
+
+    let (token_id, token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    let sub_dependency_1 = env.accounts().generate();
+    let sub_dependency_1_id = Identifier::Account(sub_dependency_1.clone());
+
+    let child_children: Vec<Recipient> = vec![
+        &env,
+        Recipient {
+            dest: soroban_sdk::Address::Account(sub_dependency_1.clone()),
+            name: symbol!("subdep_1"),
+            percentage: 50,
+            tokens: vec![&env]
+        }
+    ];
+
+    child_contract_client.initialize(&vec![&env, token_id.clone()], &child_children, &admin_id, &admin_id);
+    child_contract_client.with_source_account(&admin).s_parents(&Signature::Invoker, &BigInt::zero(&env), &vec![&env, contract_id.clone()]);
+    child_contract_client.with_source_account(&admin).s_limits(
+        &Signature::Invoker,
+        &BigInt::zero(&env),
+        &Limits { depth: 1, fanout: 10 }
+    );
+
+    let children: Vec<Recipient> = vec![
+        &env,
+        Recipient {
+            dest: soroban_sdk::Address::Contract(child_contract_id.clone()),
+            name: symbol!("c_parent_1"),
+            percentage: 20,
+            tokens: vec![&env]
+        }
+    ];
+
+    contract_client.initialize(&vec![&env, token_id.clone()], &children, &admin_id, &admin_id);
+
+    token_client.with_source_account(&admin).mint(
+        &Signature::Invoker,
+        &BigInt::zero(&env),
+        &donor_id,
+        &BigInt::from_u32(&env, 1000)
+    );
+
+    token_client.with_source_account(&donor).approve(
+        &Signature::Invoker,
+        &BigInt::zero(&env),
+        &Identifier::Contract(contract_id.clone()),
+        &BigInt::from_u32(&env, 1000)
+    );
+
+    contract_client.with_source_account(&donor).donate(&token_id, &BigInt::from_u32(&env, 1000), &donor_id);
+
+    assert_eq!(child_contract_client.held(&token_id), BigInt::from_u32(&env, 200), "Child cascade holds the donation");
+    assert_eq!(token_client.balance(&sub_dependency_1_id), BigInt::zero(&env), "Held donations aren't split yet");
+
+    child_contract_client.flush(&token_id);
+
+    assert_eq!(child_contract_client.held(&token_id), BigInt::zero(&env), "Held donations were flushed");
+    assert_eq!(
+        token_client.balance(&sub_dependency_1_id),
+        &BigInt::from_u32(&env, 100),
+        "Sub Dependency 1 receives its share after the flush"
+    );
+}
+
+#[test]
+#[should_panic(expected = "The cascade is paused")]
+fn flush_while_paused() {
+    let env = Env::default();
+
+    let admin = env.accounts().generate();
+    let admin_id = Identifier::Account(admin.clone());
+
+    let contract_id = env.register_contract(None, CascadingDonationContract);
+    let contract_client = CascadingDonationContractClient::new(&env, &contract_id);
+
+    let (token_id, _token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    contract_client.initialize(&vec![&env, token_id.clone()], &vec![&env], &admin_id, &admin_id);
+    contract_client.with_source_account(&admin).pause(&Signature::Invoker, &BigInt::zero(&env));
+
+    contract_client.flush(&token_id);
+}
+
+#[test]
+#[should_panic(expected = "The cascade can't have more than 1 children")]
+fn set_children_over_fanout_limit() {
+    let env = Env::default();
+
+    let admin = env.accounts().generate();
+    let admin_id = Identifier::Account(admin.clone());
+
+    let contract_id = env.register_contract(None, CascadingDonationContract);
+    let contract_client = CascadingDonationContractClient::new(&env, &contract_id);
+
+    let (token_id, _token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    contract_client.initialize(&vec![&env, token_id.clone()], &vec![&env], &admin_id, &admin_id);
+    contract_client.with_source_account(&admin).s_limits(
+        &Signature::Invoker,
+        &BigInt::zero(&env),
+        &Limits { depth: 4, fanout: 1 }
+    );
+
+    let dependency_1 = env.accounts().generate();
+    let dependency_2 = env.accounts().generate();
+
+    let children: Vec<Recipient> = vec![
+        &env,
+        Recipient {
+            dest: soroban_sdk::Address::Account(dependency_1.clone()),
+            name: symbol!("dep_1"),
+            percentage: 10,
+            tokens: vec![&env]
+        },
+        Recipient {
+            dest: soroban_sdk::Address::Account(dependency_2.clone()),
+            name: symbol!("dep_2"),
+            percentage: 30,
+            tokens: vec![&env]
+        }
+    ];
+
+    contract_client.with_source_account(&admin).s_children(&Signature::Invoker, &BigInt::zero(&env), &children);
+}
+
+#[test]
+fn pull_mode_donation() {
+    let env = Env::default();
+
+    let admin = env.accounts().generate();
+    let admin_id = Identifier::Account(admin.clone());
+
+    let donor = env.accounts().generate();
+    let donor_id = Identifier::Account(donor.clone());
+
+    let contract_id = env.register_contract(None, CascadingDonationContract);
+    let contract_client = CascadingDonationContractClient::new(&env, &contract_id);
+
+    let child_contract_id = env.register_contract(None, CascadingDonationContract);
+    let child_contract_client = CascadingDonationContractClient::new(&env, &child_contract_id);
+
+    let (token_id, token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    let dependency_1 = env.accounts().generate();
+    let dependency_1_id = Identifier::Account(dependency_1.clone());
+
+    let sub_dependency_1 = env.accounts().generate();
+    let sub_dependency_1_id = Identifier::Account(sub_dependency_1.clone());
+
+    let child_children: Vec<Recipient> = vec![
+        &env,
+        Recipient {
+            dest: soroban_sdk::Address::Account(sub_dependency_1.clone()),
+            name: symbol!("subdep_1"),
+            percentage: 50,
+            tokens: vec![&env]
+        }
+    ];
+
+    child_contract_client.initialize(&vec![&env, token_id.clone()], &child_children, &admin_id, &admin_id);
+    child_contract_client.with_source_account(&admin).s_parents(&Signature::Invoker, &BigInt::zero(&env), &vec![&env, contract_id.clone()]);
+
+    let children: Vec<Recipient> = vec![
+        &env,
+        Recipient {
+            dest: soroban_sdk::Address::Account(dependency_1.clone()),
+            name: symbol!("dep_1"),
+            percentage: 10,
+            tokens: vec![&env]
+        },
+        Recipient {
+            dest: soroban_sdk::Address::Contract(child_contract_id.clone()),
+            name: symbol!("c_parent_1"),
+            percentage: 20,
+            tokens: vec![&env]
+        }
+    ];
+
+    contract_client.initialize(&vec![&env, token_id.clone()], &children, &admin_id, &admin_id);
+    contract_client.with_source_account(&admin).s_pull(&Signature::Invoker, &BigInt::zero(&env), &true);
+
+    token_client.with_source_account(&admin).mint(
+        &Signature::Invoker,
+        &BigInt::zero(&env),
+        &donor_id,
+        &BigInt::from_u32(&env, 1000)
+    );
+
+    token_client.with_source_account(&donor).approve(
+        &Signature::Invoker,
+        &BigInt::zero(&env),
+        &Identifier::Contract(contract_id.clone()),
+        &BigInt::from_u32(&env, 1000)
+    );
+
+    contract_client.with_source_account(&donor).donate(&token_id, &BigInt::from_u32(&env, 1000), &donor_id);
+
+    assert_eq!(
+        contract_client.pending(&soroban_sdk::Address::Account(dependency_1.clone()), &token_id),
+        BigInt::from_u32(&env, 100),
+        "Dependency 1 share is accrued"
+    );
+    assert_eq!(token_client.balance(&dependency_1_id), BigInt::zero(&env), "Accrued shares aren't transferred yet");
+
+    contract_client.withdraw(&soroban_sdk::Address::Account(dependency_1.clone()), &token_id);
+    contract_client.poke(&soroban_sdk::Address::Contract(child_contract_id.clone()), &token_id);
+
+    assert_eq!(token_client.balance(&dependency_1_id), &BigInt::from_u32(&env, 100), "Dependency 1 withdraws its share");
+    assert_eq!(
+        token_client.balance(&sub_dependency_1_id),
+        &BigInt::from_u32(&env, 100),
+        "Poking the child cascade keeps the donation cascading"
+    );
+    assert_eq!(
+        contract_client.pending(&soroban_sdk::Address::Contract(child_contract_id.clone()), &token_id),
+        BigInt::zero(&env),
+        "Child cascade share was collected"
+    );
+}
+
+#[test]
+#[should_panic(expected = "The cascade is paused")]
+fn withdraw_while_paused() {
+    let env = Env::default();
+
+    let admin = env.accounts().generate();
+    let admin_id = Identifier::Account(admin.clone());
+
+    let dependency_1 = env.accounts().generate();
+
+    let contract_id = env.register_contract(None, CascadingDonationContract);
+    let contract_client = CascadingDonationContractClient::new(&env, &contract_id);
+
+    let (token_id, _token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    contract_client.initialize(&vec![&env, token_id.clone()], &vec![&env], &admin_id, &admin_id);
+    contract_client.with_source_account(&admin).pause(&Signature::Invoker, &BigInt::zero(&env));
+
+    contract_client.withdraw(&soroban_sdk::Address::Account(dependency_1.clone()), &token_id);
+}
+
+#[test]
+#[should_panic(expected = "The cascade is paused")]
+fn poke_while_paused() {
+    let env = Env::default();
+
+    let admin = env.accounts().generate();
+    let admin_id = Identifier::Account(admin.clone());
+
+    let child_contract_id = env.register_contract(None, CascadingDonationContract);
+
+    let contract_id = env.register_contract(None, CascadingDonationContract);
+    let contract_client = CascadingDonationContractClient::new(&env, &contract_id);
+
+    let (token_id, _token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    contract_client.initialize(&vec![&env, token_id.clone()], &vec![&env], &admin_id, &admin_id);
+    contract_client.with_source_account(&admin).pause(&Signature::Invoker, &BigInt::zero(&env));
+
+    contract_client.poke(&soroban_sdk::Address::Contract(child_contract_id.clone()), &token_id);
+}
+
+#[test]
+fn donation_in_several_tokens() {
+    let env = Env::default();
+
+    let admin = env.accounts().generate();
+    let admin_id = Identifier::Account(admin.clone());
+
+    let donor = env.accounts().generate();
+    let donor_id = Identifier::Account(donor.clone());
+
+    let contract_id = env.register_contract(None, CascadingDonationContract);
+    let contract_client = CascadingDonationContractClient::new(&env, &contract_id);
+
+    let (token_id, token_client) = create_and_init_token_contract(&env, &admin_id);
+    let (other_token_id, other_token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    let dependency_1 = env.accounts().generate();
+    let dependency_1_id = Identifier::Account(dependency_1.clone());
+
+    let dependency_2 = env.accounts().generate();
+    let dependency_2_id = Identifier::Account(dependency_2.clone());
+
+    let children: Vec<Recipient> = vec![
+        &env,
+        Recipient {
+            dest: soroban_sdk::Address::Account(dependency_1.clone()),
+            name: symbol!("dep_1"),
+            percentage: 10,
+            tokens: vec![&env]
+        },
+        Recipient {
+            dest: soroban_sdk::Address::Account(dependency_2.clone()),
+            name: symbol!("dep_2"),
+            percentage: 30,
+            tokens: vec![&env, token_id.clone()]
+        }
+    ];
+
+    contract_client.initialize(&vec![&env, token_id.clone(), other_token_id.clone()], &children, &admin_id, &admin_id);
+
+    for (id, client) in [(&token_id, &token_client), (&other_token_id, &other_token_client)] {
+        client.with_source_account(&admin).mint(
+            &Signature::Invoker,
+            &BigInt::zero(&env),
+            &donor_id,
+            &BigInt::from_u32(&env, 1000)
+        );
+
+        client.with_source_account(&donor).approve(
+            &Signature::Invoker,
+            &BigInt::zero(&env),
+            &Identifier::Contract(contract_id.clone()),
+            &BigInt::from_u32(&env, 1000)
+        );
+
+        contract_client.with_source_account(&donor).donate(id, &BigInt::from_u32(&env, 1000), &donor_id);
+    }
+
+    assert_eq!(token_client.balance(&dependency_1_id), &BigInt::from_u32(&env, 100), "Dependency 1 receives the first token");
+    assert_eq!(other_token_client.balance(&dependency_1_id), &BigInt::from_u32(&env, 100), "Dependency 1 receives the second token");
+    assert_eq!(token_client.balance(&dependency_2_id), &BigInt::from_u32(&env, 300), "Dependency 2 receives the token it accepts");
+    assert_eq!(other_token_client.balance(&dependency_2_id), BigInt::zero(&env), "Dependency 2 doesn't receive other tokens");
+
+    assert_eq!(contract_client.retained(&token_id), BigInt::from_u32(&env, 600), "Retained share of the first token");
+    assert_eq!(contract_client.retained(&other_token_id), BigInt::from_u32(&env, 900), "Retained share of the second token");
+}
+
+#[test]
+#[should_panic(expected = "The token isn't allowed by the cascade")]
+fn donation_in_token_not_allowed() {
+    let env = Env::default();
+
+    let admin = env.accounts().generate();
+    let admin_id = Identifier::Account(admin.clone());
+
+    let donor = env.accounts().generate();
+    let donor_id = Identifier::Account(donor.clone());
+
+    let contract_id = env.register_contract(None, CascadingDonationContract);
+    let contract_client = CascadingDonationContractClient::new(&env, &contract_id);
+
+    let (token_id, _token_client) = create_and_init_token_contract(&env, &admin_id);
+    let (other_token_id, _other_token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    contract_client.initialize(&vec![&env, token_id.clone()], &vec![&env], &admin_id, &admin_id);
+
+    contract_client.with_source_account(&donor).donate(&other_token_id, &BigInt::from_u32(&env, 1000), &donor_id);
+}
+
+#[test]
+fn contract_children_take_the_child_tokens() {
+    let env = Env::default();
+
+    let admin = env.accounts().generate();
+    let admin_id = Identifier::Account(admin.clone());
+
+    let contract_id = env.register_contract(None, CascadingDonationContract);
+    let contract_client = CascadingDonationContractClient::new(&env, &contract_id);
+
+    let child_contract_id = env.register_contract(None, CascadingDonationContract);
+    let child_contract_client = CascadingDonationContractClient::new(&env, &child_contract_id);
+
+    let (token_id, _token_client) = create_and_init_token_contract(&env, &admin_id);
+    let (other_token_id, _other_token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    child_contract_client.initialize(&vec![&env, token_id.clone()], &vec![&env], &admin_id, &admin_id);
+
+    let children: Vec<Recipient> = vec![
+        &env,
+        Recipient {
+            dest: soroban_sdk::Address::Contract(child_contract_id.clone()),
+            name: symbol!("c_parent_1"),
+            percentage: 20,
+            tokens: vec![&env]
+        }
+    ];
+
+    contract_client.initialize(&vec![&env, token_id.clone(), other_token_id.clone()], &children, &admin_id, &admin_id);
+
+    assert_eq!(
+        contract_client.g_children().get(0).unwrap().unwrap().tokens,
+        vec![&env, token_id.clone()],
+        "The child reports the tokens it allows"
+    );
+}
+
+#[test]
+#[should_panic(expected = "doesn't allow every token it's given")]
+fn contract_child_with_token_not_allowed() {
+    let env = Env::default();
+
+    let admin = env.accounts().generate();
+    let admin_id = Identifier::Account(admin.clone());
+
+    let contract_id = env.register_contract(None, CascadingDonationContract);
+    let contract_client = CascadingDonationContractClient::new(&env, &contract_id);
+
+    let child_contract_id = env.register_contract(None, CascadingDonationContract);
+    let child_contract_client = CascadingDonationContractClient::new(&env, &child_contract_id);
+
+    let (token_id, _token_client) = create_and_init_token_contract(&env, &admin_id);
+    let (other_token_id, _other_token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    child_contract_client.initialize(&vec![&env, token_id.clone()], &vec![&env], &admin_id, &admin_id);
+
+    let children: Vec<Recipient> = vec![
+        &env,
+        Recipient {
+            dest: soroban_sdk::Address::Contract(child_contract_id.clone()),
+            name: symbol!("c_parent_1"),
+            percentage: 20,
+            tokens: vec![&env, other_token_id.clone()]
+        }
+    ];
+
+    contract_client.initialize(&vec![&env, token_id.clone(), other_token_id.clone()], &children, &admin_id, &admin_id);
+}
+
+#[test]
+fn preview_donation_tree() {
+    let env = Env::default();
+
+    let admin = env.accounts().generate();
+    let admin_id = Identifier::Account(admin.clone());
+
+    let donor = env.accounts().generate();
+    let donor_id = Identifier::Account(donor.clone());
+
+    let contract_id = env.register_contract(None, CascadingDonationContract);
+    let contract_client = CascadingDonationContractClient::new(&env, &contract_id);
+
+    let child_contract_id = env.register_contract(None, CascadingDonationContract);
+    let child_contract_client = CascadingDonationContractClient::new(&env, &child_contract_id);
+
+    let (token_id, token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    let dependency_1 = env.accounts().generate();
+
+    let sub_dependency_1 = env.accounts().generate();
+    let sub_dependency_1_id = Identifier::Account(sub_dependency_1.clone());
+
+    let child_children: Vec<Recipient> = vec![
+        &env,
+        Recipient {
+            dest: soroban_sdk::Address::Account(sub_dependency_1.clone()),
+            name: symbol!("subdep_1"),
+            percentage: 33,
+            tokens: vec![&env]
+        }
+    ];
+
+    child_contract_client.initialize(&vec![&env, token_id.clone()], &child_children, &admin_id, &admin_id);
+    child_contract_client.with_source_account(&admin).s_parents(&Signature::Invoker, &BigInt::zero(&env), &vec![&env, contract_id.clone()]);
+
+    let children: Vec<Recipient> = vec![
+        &env,
+        Recipient {
+            dest: soroban_sdk::Address::Account(dependency_1.clone()),
+            name: symbol!("dep_1"),
+            percentage: 40,
+            tokens: vec![&env]
+        },
+        Recipient {
+            dest: soroban_sdk::Address::Contract(child_contract_id.clone()),
+            name: symbol!("c_parent_1"),
+            percentage: 30,
+            tokens: vec![&env]
+        }
+    ];
+
+    contract_client.initialize(&vec![&env, token_id.clone()], &children, &admin_id, &admin_id);
+
+    let expected: Vec<Payout> = vec![
+        &env,
+        Payout {
+            name: symbol!("dep_1"),
+            dest: soroban_sdk::Address::Account(dependency_1.clone()),
+            parent: soroban_sdk::Address::Contract(contract_id.clone()),
+            amount: BigInt::from_u32(&env, 40),
+            depth: 1
+        },
+        Payout {
+            name: symbol!("c_parent_1"),
+            dest: soroban_sdk::Address::Contract(child_contract_id.clone()),
+            parent: soroban_sdk::Address::Contract(contract_id.clone()),
+            amount: BigInt::from_u32(&env, 30),
+            depth: 1
+        },
+        Payout {
+            name: symbol!("subdep_1"),
+            dest: soroban_sdk::Address::Account(sub_dependency_1.clone()),
+            parent: soroban_sdk::Address::Contract(child_contract_id.clone()),
+            amount: BigInt::from_u32(&env, 9),
+            depth: 2
+        }
+    ];
+
+    assert_eq!(contract_client.preview(&token_id, &BigInt::from_u32(&env, 100)), expected, "Preview returns the payout tree");
+
+    token_client.with_source_account(&admin).mint(
+        &Signature::Invoker,
+        &BigInt::zero(&env),
+        &donor_id,
+        &BigInt::from_u32(&env, 100)
+    );
+
+    token_client.with_source_account(&donor).approve(
+        &Signature::Invoker,
+        &BigInt::zero(&env),
+        &Identifier::Contract(contract_id.clone()),
+        &BigInt::from_u32(&env, 100)
+    );
+
+    contract_client.with_source_account(&donor).donate(&token_id, &BigInt::from_u32(&env, 100), &donor_id);
+
+    assert_eq!(
+        token_client.balance(&sub_dependency_1_id),
+        &BigInt::from_u32(&env, 9),
+        "Donation rounds like the preview"
+    );
+}
+
+#[test]
+fn preview_while_paused() {
+    let env = Env::default();
+
+    let admin = env.accounts().generate();
+    let admin_id = Identifier::Account(admin.clone());
+
+    let dependency_1 = env.accounts().generate();
+
+    let contract_id = env.register_contract(None, CascadingDonationContract);
+    let contract_client = CascadingDonationContractClient::new(&env, &contract_id);
+
+    let (token_id, _token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    let children: Vec<Recipient> = vec![
+        &env,
+        Recipient {
+            dest: soroban_sdk::Address::Account(dependency_1.clone()),
+            name: symbol!("dep_1"),
+            percentage: 40,
+            tokens: vec![&env]
+        }
+    ];
+
+    contract_client.initialize(&vec![&env, token_id.clone()], &children, &admin_id, &admin_id);
+    contract_client.with_source_account(&admin).pause(&Signature::Invoker, &BigInt::zero(&env));
+
+    let payouts = contract_client.preview(&token_id, &BigInt::from_u32(&env, 100));
+
+    assert_eq!(payouts.len(), 1, "Donations can be previewed while paused");
+    assert_eq!(payouts.get(0).unwrap().unwrap().amount, BigInt::from_u32(&env, 40), "Preview returns the payout tree");
+}
+
+#[test]
+fn cascade_tree() {
+    let env = Env::default();
+
+    let admin = env.accounts().generate();
+    let admin_id = Identifier::Account(admin.clone());
+
+    let contract_id = env.register_contract(None, CascadingDonationContract);
+    let contract_client = CascadingDonationContractClient::new(&env, &contract_id);
+
+    let child_contract_id = env.register_contract(None, CascadingDonationContract);
+    let child_contract_client = CascadingDonationContractClient::new(&env, &child_contract_id);
+
+    let (token_id, _token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    let dependency_1 = env.accounts().generate();
+    let sub_dependency_1 = env.accounts().generate();
+
+    let child_children: Vec<Recipient> = vec![
+        &env,
+        Recipient {
+            dest: soroban_sdk::Address::Account(sub_dependency_1.clone()),
+            name: symbol!("subdep_1"),
+            percentage: 25,
+            tokens: vec![&env]
+        }
+    ];
+
+    child_contract_client.initialize(&vec![&env, token_id.clone()], &child_children, &admin_id, &admin_id);
+
+    let children: Vec<Recipient> = vec![
+        &env,
+        Recipient {
+            dest: soroban_sdk::Address::Account(dependency_1.clone()),
+            name: symbol!("dep_1"),
+            percentage: 10,
+            tokens: vec![&env]
+        },
+        Recipient {
+            dest: soroban_sdk::Address::Contract(child_contract_id.clone()),
+            name: symbol!("c_parent_1"),
+            percentage: 20,
+            tokens: vec![&env]
+        }
+    ];
+
+    contract_client.initialize(&vec![&env, token_id.clone()], &children, &admin_id, &admin_id);
+
+    let expected: Vec<Node> = vec![
+        &env,
+        Node {
+            name: symbol!("dep_1"),
+            dest: soroban_sdk::Address::Account(dependency_1.clone()),
+            parent: soroban_sdk::Address::Contract(contract_id.clone()),
+            percentage: 10,
+            share: 100_000,
+            depth: 1
+        },
+        Node {
+            name: symbol!("c_parent_1"),
+            dest: soroban_sdk::Address::Contract(child_contract_id.clone()),
+            parent: soroban_sdk::Address::Contract(contract_id.clone()),
+            percentage: 20,
+            share: 200_000,
+            depth: 1
+        },
+        Node {
+            name: symbol!("subdep_1"),
+            dest: soroban_sdk::Address::Account(sub_dependency_1.clone()),
+            parent: soroban_sdk::Address::Contract(child_contract_id.clone()),
+            percentage: 25,
+            share: 50_000,
+            depth: 2
+        }
+    ];
+
+    assert_eq!(contract_client.g_tree(), expected, "Tree has every node of the cascade");
+}
+
+#[test]
+fn donor_receipts() {
+    let env = Env::default();
+
+    let admin = env.accounts().generate();
+    let admin_id = Identifier::Account(admin.clone());
+
+    let donor = env.accounts().generate();
+    let donor_id = Identifier::Account(donor.clone());
+
+    let contract_id = env.register_contract(None, CascadingDonationContract);
+    let contract_client = CascadingDonationContractClient::new(&env, &contract_id);
+
+    let (token_id, token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    let dependency_1 = env.accounts().generate();
+
+    let children: Vec<Recipient> = vec![
+        &env,
+        Recipient {
+            dest: soroban_sdk::Address::Account(dependency_1.clone()),
+            name: symbol!("dep_1"),
+            percentage: 10,
+            tokens: vec![&env]
+        }
+    ];
+
+    contract_client.initialize(&vec![&env, token_id.clone()], &children, &admin_id, &admin_id);
+
+    token_client.with_source_account(&admin).mint(
+        &Signature::Invoker,
+        &BigInt::zero(&env),
+        &donor_id,
+        &BigInt::from_u32(&env, 1000)
+    );
+
+    token_client.with_source_account(&donor).approve(
+        &Signature::Invoker,
+        &BigInt::zero(&env),
+        &Identifier::Contract(contract_id.clone()),
+        &BigInt::from_u32(&env, 1000)
+    );
+
+    let first = contract_client.with_source_account(&donor).donate(&token_id, &BigInt::from_u32(&env, 400), &donor_id);
+
+    contract_client.with_source_account(&admin).s_children(&Signature::Invoker, &BigInt::zero(&env), &children);
+
+    let second = contract_client.with_source_account(&donor).donate(&token_id, &BigInt::from_u32(&env, 600), &donor_id);
+
+    assert_eq!(contract_client.receipt(&first).amount, BigInt::from_u32(&env, 400), "Receipt keeps the amount");
+    assert_eq!(contract_client.receipt(&first).children_v, 1, "Receipt keeps the children version");
+    assert_eq!(contract_client.receipt(&second).children_v, 2, "Changing the children bumps the version");
+
+    assert_eq!(contract_client.donor_tot(&donor_id, &token_id), BigInt::from_u32(&env, 1000), "Donor lifetime total");
+    assert_eq!(contract_client.raised(&token_id), BigInt::from_u32(&env, 1000), "Total raised");
+
+    let history = contract_client.history(&donor_id, &1, &10);
+
+    assert_eq!(history.len(), 1, "History is paginated");
+    assert_eq!(history.get(0).unwrap().unwrap(), contract_client.receipt(&second), "History returns the receipts");
+}
@@ -1,8 +1,8 @@
 #![no_std]
 
-use soroban_sdk::{contractimpl, contracttype, vec, Env, Symbol, Vec, BytesN, AccountId, BigInt, RawVal, Map};
+use soroban_sdk::{contractimpl, contracttype, symbol, vec, Env, Symbol, Vec, Bytes, BytesN, AccountId, Address, BigInt, RawVal, Map};
 
-use soroban_auth::{Identifier, Signature};
+use soroban_auth::{verify, Identifier, Signature};
 
 mod token {
     soroban_sdk::contractimport!(file = "./soroban_token_spec.wasm");
@@ -16,7 +16,307 @@ pub enum DataKey {
     AdminId,
     Rewards,
     Members,
-    AllowedF
+    AllowedF,
+    Nonce(Identifier),
+    KudosCfg,
+    KudosUsed(AccountId),
+    KudosRecv(AccountId),
+    Member(AccountId),
+    SuspUntil(AccountId),
+    Tiers,
+    MTier(AccountId),
+    Earned(AccountId),
+    Applicants,
+    Applicant(AccountId),
+    Invite(BytesN<32>),
+    Rep(AccountId),
+    RepDecay,
+    RwdCount(RewardKey),
+    RwdTotal(AccountId),
+    Badges,
+    MBadges(AccountId),
+    Holders(Symbol),
+    Paused,
+    SchemaV,
+    Teams,
+    Team(Symbol),
+    TeamSpent(Symbol),
+    Stream(u32),
+    StreamId,
+    MStreams(AccountId),
+    Epoch(u32),
+    EpochId,
+    EpPoints(EpochKey),
+    EpClaimed(EpochKey),
+    GovCfg,
+    Proposal(u32),
+    PropId,
+    Voted(VoteKey),
+    Bounty(u32),
+    BountyId,
+    DisputeW,
+    Reward(u32),
+    RewardId,
+    MRewards(AccountId)
+}
+
+// Bumped whenever the storage layout changes, so deployments can tell which layout they hold.
+const SCHEMA_VERSION: u32 = 1;
+
+#[derive(Clone)]
+#[contracttype]
+pub struct RewardKey {
+    pub account: AccountId,
+    pub r_type: Symbol,
+}
+
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct RewardCount {
+    pub r_type: Symbol,
+    pub count: u32,
+}
+
+// Manual badges are only awarded by the admin, the rest are awarded automatically.
+#[derive(Clone, Debug)]
+#[contracttype]
+pub enum BadgeRule {
+    Manual,
+    FirstRwd,
+    RwdCount(RewardCount),
+    Tenure(u64)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum MemberStatus {
+    Active,
+    Suspended,
+    Alumni
+}
+
+// The multiplier is a percentage applied to the reward values, 100 keeps them unchanged.
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct Tier {
+    pub name: Symbol,
+    pub threshold: u64,
+    pub multiplier: u32,
+}
+
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct Application {
+    pub account: AccountId,
+    pub metadata: BytesN<32>,
+}
+
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct Invite {
+    pub expires: u64,
+    pub uses_left: u32,
+}
+
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct Reputation {
+    pub points: u64,
+    pub updated: u64,
+}
+
+// The rate is the percentage of reputation points lost on every elapsed period.
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct RepDecay {
+    pub rate: u32,
+    pub period: u64,
+}
+
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct RepEntry {
+    pub account: AccountId,
+    pub points: u64,
+}
+
+// Teams are funded from the treasury, the manager can spend up to the budget on every period.
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct Team {
+    pub manager: AccountId,
+    pub budget: u32,
+    pub period: u64,
+    pub members: Vec<AccountId>,
+}
+
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct TeamSpend {
+    pub period: u64,
+    pub spent: u32,
+    pub total: u64,
+}
+
+// The stream releases `rate` tokens every second between `start` and `end`.
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct Stream {
+    pub member: AccountId,
+    pub rate: u32,
+    pub start: u64,
+    pub end: u64,
+    pub total: u64,
+    pub withdrawn: u64,
+    pub active: bool,
+}
+
+// The pool is split among the members in proportion to the points they accrued during the epoch.
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct Epoch {
+    pub pool: u64,
+    pub end: u64,
+    pub claim_end: u64,
+    pub points: u64,
+    pub claimed: u64,
+    pub finalized: bool,
+    pub swept: bool,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct EpochKey {
+    pub epoch: u32,
+    pub account: AccountId,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum VoteWeight {
+    Member,
+    Reputation
+}
+
+// Proposals need `quorum` votes to pass and can be executed `delay` seconds after the voting period.
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct GovConfig {
+    pub weight: VoteWeight,
+    pub quorum: u64,
+    pub period: u64,
+    pub delay: u64,
+}
+
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct RewardValue {
+    pub r_type: Symbol,
+    pub value: u32,
+}
+
+#[derive(Clone, Debug)]
+#[contracttype]
+pub enum Action {
+    SetReward(RewardValue),
+    RmReward(Symbol),
+    SetAllowed(BigInt),
+    AddMember(AccountId)
+}
+
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct Proposal {
+    pub proposer: AccountId,
+    pub action: Action,
+    pub votes_for: u64,
+    pub against: u64,
+    pub end: u64,
+    pub executed: bool,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct VoteKey {
+    pub proposal: u32,
+    pub account: AccountId,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Submission {
+    pub claimant: AccountId,
+    pub work: BytesN<32>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum BountyState {
+    Open,
+    Claimed(AccountId),
+    Submitted(Submission),
+    Released(AccountId),
+    Cancelled,
+    Expired
+}
+
+// The bounty amount is escrowed by this contract until it's released, cancelled or expired.
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct Bounty {
+    pub desc: BytesN<32>,
+    pub amount: u64,
+    pub deadline: u64,
+    pub reviewer: AccountId,
+    pub state: BountyState,
+}
+
+// Team rewards keep the team they were paid from, so reversing them refunds its budget.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum RewardSrc {
+    Direct,
+    Team(Symbol)
+}
+
+// Rewards can be reversed until `until`, which is set from the dispute window when they're given.
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct Reward {
+    pub member: AccountId,
+    pub source: RewardSrc,
+    pub r_type: Symbol,
+    pub amount: u32,
+    pub points: u64,
+    pub payout: AccountId,
+    pub time: u64,
+    pub until: u64,
+    pub reversed: bool,
+}
+
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct Member {
+    pub account: AccountId,
+    pub joined: u64,
+    pub name: Symbol,
+    pub role: Symbol,
+    pub status: MemberStatus,
+    pub payout: AccountId,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct KudosConfig {
+    pub budget: u32,
+    pub period: u64,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct KudosUsage {
+    pub period: u64,
+    pub spent: u32,
 }
 
 // VALIDATIONS
@@ -26,17 +326,261 @@ fn is_member(env: &Env, to: &AccountId) -> bool {
     members.contains(to)
 } 
 
+// AUTHORIZATION
+fn read_nonce(env: &Env, id: &Identifier) -> BigInt {
+    let key = DataKey::Nonce(id.clone());
+    env.data()
+        .get(key)
+        .unwrap_or(Ok(BigInt::zero(&env)))
+        .unwrap()
+}
+
+fn verify_and_consume_nonce(env: &Env, auth: &Signature, expected_nonce: &BigInt) {
+    match auth {
+        Signature::Invoker => {
+            if BigInt::zero(&env) != expected_nonce {
+                panic!("nonce should be zero for Invoker")
+            }
+            return;
+        }
+        _ => {}
+    }
+
+    let id = auth.identifier(&env);
+    let nonce = read_nonce(&env, &id);
+
+    if nonce != expected_nonce {
+        panic!("incorrect nonce")
+    }
+
+    env.data().set(DataKey::Nonce(id), &nonce + 1);
+}
+
+fn check_admin(env: &Env, auth: &Signature, nonce: &BigInt) {
+    if auth.identifier(&env) != get_admin_id(&env) {
+        panic!("Only the organization's admin can perform this action");
+    }
+
+    verify_and_consume_nonce(&env, &auth, &nonce);
+}
+
+// PAUSE
+fn is_paused(env: &Env) -> bool {
+    let key = DataKey::Paused;
+    env.data().get(key).unwrap_or(Ok(false)).unwrap()
+}
+
+fn set_paused(env: &Env, paused: bool) {
+    env.data().set(DataKey::Paused, paused);
+
+    if paused {
+        env.events().publish((symbol!("paused"),), get_admin_id(&env));
+    } else {
+        env.events().publish((symbol!("unpaused"),), get_admin_id(&env));
+    }
+}
+
+// Read-only queries never call this, so they keep working while paused.
+fn check_not_paused(env: &Env) {
+    if is_paused(&env) {
+        panic!("The organization is paused");
+    }
+}
+
+fn get_invoker_account(env: &Env) -> AccountId {
+    match env.invoker() {
+        Address::Account(account_id) => account_id,
+        Address::Contract(_) => panic!("This action can only be invoked by an account"),
+    }
+}
+
 // ORGANIZATION
 fn add_member(env: &Env, account: AccountId) {
+    if is_member(&env, &account) {
+        panic!("The account already belongs to the organization");
+    }
+
     let mut members = get_members(&env);
 
-    members.push_back(account);
+    members.push_back(account.clone());
 
     let key = DataKey::Members;
     env.data().set(key, members);
+
+    set_member(&env, &Member {
+        account: account.clone(),
+        joined: env.ledger().timestamp(),
+        name: symbol!(""),
+        role: symbol!(""),
+        status: MemberStatus::Active,
+        payout: account
+    });
+}
+
+fn set_member(env: &Env, member: &Member) {
+    env.data().set(DataKey::Member(member.account.clone()), member);
+}
+
+fn get_member(env: &Env, account: &AccountId) -> Member {
+    let key = DataKey::Member(account.clone());
+
+    let mut member: Member = match env.data().get(key) {
+        Some(member) => member.unwrap(),
+        None => panic!("The account doesn't belong to the organization"),
+    };
+
+    // Expired suspensions are lifted when the member is read
+    if member.status == MemberStatus::Suspended && is_suspension_expired(&env, &account) {
+        member.status = MemberStatus::Active;
+    }
+
+    member
+}
+
+// APPLICATIONS
+fn get_applicants(env: &Env) -> Vec<AccountId> {
+    let key = DataKey::Applicants;
+    env.data()
+        .get(key)
+        .unwrap_or(Ok(vec![&env]))
+        .unwrap()
+}
+
+fn apply_for_membership(env: &Env, account: &AccountId, metadata: &BytesN<32>) {
+    if is_member(&env, &account) {
+        panic!("The account already belongs to the organization");
+    }
+
+    let mut applicants = get_applicants(&env);
+
+    if applicants.contains(account) {
+        panic!("The account already has a pending application");
+    }
+
+    applicants.push_back(account.clone());
+
+    env.data().set(DataKey::Applicants, applicants);
+    env.data().set(DataKey::Applicant(account.clone()), metadata);
+}
+
+fn close_application(env: &Env, account: &AccountId) {
+    let mut applicants = get_applicants(&env);
+
+    match applicants.first_index_of(account) {
+        Some(index) => applicants.remove(index),
+        None => panic!("The account doesn't have a pending application"),
+    };
+
+    env.data().set(DataKey::Applicants, applicants);
+    env.data().remove(DataKey::Applicant(account.clone()));
+}
+
+fn get_applications(env: &Env) -> Vec<Application> {
+    let mut applications: Vec<Application> = vec![&env];
+
+    for account in get_applicants(&env).iter() {
+        let account = account.unwrap();
+        let metadata: BytesN<32> = env.data().get(DataKey::Applicant(account.clone())).unwrap().unwrap();
+
+        applications.push_back(Application { account, metadata });
+    }
+
+    applications
+}
+
+// INVITES
+fn set_invite(env: &Env, code_hash: &BytesN<32>, invite: &Invite) {
+    env.data().set(DataKey::Invite(code_hash.clone()), invite);
+}
+
+// Invite codes are published hashed, the account redeeming one provides the code itself.
+fn redeem_invite(env: &Env, account: &AccountId, code: &Bytes) {
+    let code_hash = env.compute_hash_sha256(code.clone());
+    let key = DataKey::Invite(code_hash.clone());
+
+    let mut invite: Invite = match env.data().get(key) {
+        Some(invite) => invite.unwrap(),
+        None => panic!("The invite code isn't valid"),
+    };
+
+    if env.ledger().timestamp() >= invite.expires {
+        panic!("The invite code has expired");
+    }
+
+    if invite.uses_left == 0 {
+        panic!("The invite code has no uses left");
+    }
+
+    invite.uses_left -= 1;
+    set_invite(&env, &code_hash, &invite);
+
+    add_member(&env, account.clone());
+}
+
+// SUSPENSIONS
+fn suspend_member(env: &Env, account: &AccountId, until: u64) {
+    let mut member = get_member(&env, &account);
+    member.status = MemberStatus::Suspended;
+    set_member(&env, &member);
+
+    env.data().set(DataKey::SuspUntil(account.clone()), until);
+}
+
+fn reinstate_member(env: &Env, account: &AccountId) {
+    let mut member = get_member(&env, &account);
+    member.status = MemberStatus::Active;
+    set_member(&env, &member);
+
+    env.data().remove(DataKey::SuspUntil(account.clone()));
+}
+
+// A suspension without an expiry timestamp (0) lasts until the member is reinstated.
+fn is_suspension_expired(env: &Env, account: &AccountId) -> bool {
+    let key = DataKey::SuspUntil(account.clone());
+    let until: u64 = env.data().get(key).unwrap_or(Ok(0)).unwrap();
+
+    until != 0 && env.ledger().timestamp() >= until
+}
+
+fn is_suspended(env: &Env, account: &AccountId) -> bool {
+    get_member(&env, &account).status == MemberStatus::Suspended
+}
+
+// Alumni keep their profile and balance but can't be rewarded, send or receive kudos, vote or claim bounties.
+fn is_alumni(env: &Env, account: &AccountId) -> bool {
+    get_member(&env, &account).status == MemberStatus::Alumni
+}
+
+fn get_members_page(env: &Env, start: u32, limit: u32) -> Vec<Member> {
+    let members: Vec<AccountId> = get_members(&env);
+    let end = core::cmp::min(start.saturating_add(limit), members.len());
+
+    let mut page: Vec<Member> = vec![&env];
+    for index in start..end {
+        page.push_back(get_member(&env, &members.get(index).unwrap().unwrap()));
+    }
+
+    page
+}
+
+// Rewards are sent to the payout address set by the admin, which defaults to the member's own account.
+fn get_payout_identifier(env: &Env, account: &AccountId) -> Identifier {
+    get_account_identifier(get_member(&env, &account).payout)
+}
+
+fn get_invoker_member(env: &Env) -> Member {
+    let account = get_invoker_account(&env);
+
+    if !is_member(&env, &account) {
+        panic!("Only members of the organization can update their profile");
+    }
+
+    get_member(&env, &account)
 }
 
 fn revoke_membership(env: &Env, from: &AccountId) {
+    check_not_paused(&env);
+
     let mut members: Vec<AccountId> = get_members(&env);
     
     let index;
@@ -66,6 +610,25 @@ fn revoke_membership(env: &Env, from: &AccountId) {
         &admin_id,
         &member_balance
     );
+
+    // The streams are closed after the clawback, so the member keeps what was already streamed
+    close_member_streams(&env, &from);
+    remove_member_records(&env, &from);
+}
+
+fn remove_member_records(env: &Env, account: &AccountId) {
+    env.data().remove(DataKey::Member(account.clone()));
+    env.data().remove(DataKey::SuspUntil(account.clone()));
+    env.data().remove(DataKey::MTier(account.clone()));
+    env.data().remove(DataKey::Earned(account.clone()));
+    env.data().remove(DataKey::Rep(account.clone()));
+    env.data().remove(DataKey::RwdTotal(account.clone()));
+    for reward_type in get_rewards(&env).keys().iter() {
+        env.data().remove(DataKey::RwdCount(RewardKey { account: account.clone(), r_type: reward_type.unwrap() }));
+    }
+    env.data().remove(DataKey::MRewards(account.clone()));
+    remove_member_badges(&env, &account);
+    remove_from_teams(&env, &account);
 }
 
 fn get_members<T: soroban_sdk::TryFromVal<Env, RawVal> + soroban_sdk::IntoVal<Env, RawVal>>(
@@ -79,6 +642,8 @@ fn get_members<T: soroban_sdk::TryFromVal<Env, RawVal> + soroban_sdk::IntoVal<En
 }
 
 fn fund_contract_balance(env: &Env, approval_sign: &Signature) {
+    check_not_paused(&env);
+
     let token_id = get_token_contract_id(&env);
     let admin_id = get_admin_id(&env);
     let token_client = token::Client::new(&env, &token_id);
@@ -87,17 +652,50 @@ fn fund_contract_balance(env: &Env, approval_sign: &Signature) {
     token_client.mint(&approval_sign, &nonce, &admin_id, &get_allowed_funds_to_issue(&env));
 }
 
-fn reward_member(env: &Env, approval_sign: &Signature, to: &AccountId, reward_type: &Symbol) {
+fn reward_member(env: &Env, approval_sign: &Signature, to: &AccountId, reward_type: &Symbol) -> u32 {
+    check_not_paused(&env);
+
+    if approval_sign.identifier(&env) != get_admin_id(&env) {
+        panic!("Only the organization's admin can approve rewards");
+    }
+
+    let reward_value = credit_reward(&env, &to, &reward_type);
+    transfer(&env, &approval_sign, &get_payout_identifier(&env, &to), &BigInt::from_u32(&env, reward_value));
+
+    record_reward(&env, &to, &RewardSrc::Direct, &reward_type, reward_value)
+}
+
+// Validates the reward and updates the member's records, returning the amount to pay.
+fn credit_reward(env: &Env, to: &AccountId, reward_type: &Symbol) -> u32 {
+    check_rewardable(&env, &to, &reward_type);
+
+    let reward_value = get_member_reward_value(&env, &to, &reward_type);
+
+    add_earned(&env, &to, reward_value);
+    promote_member(&env, &to);
+    add_reputation(&env, &to, get_reward_by_type(&env, &reward_type) as u64);
+    add_reward_count(&env, &to, &reward_type);
+    check_badges(&env, &to);
+
+    reward_value
+}
+
+fn check_rewardable(env: &Env, to: &AccountId, reward_type: &Symbol) {
     if !is_member(&env, &to) {
         panic!("The user account you're trying to reward doesn't belong to the organization");
     }
 
+    if is_suspended(&env, &to) {
+        panic!("The user account you're trying to reward is suspended");
+    }
+
+    if is_alumni(&env, &to) {
+        panic!("The user account you're trying to reward is an alumni member");
+    }
+
     if !is_reward_valid(&env, &reward_type) {
         panic!("The reward type you are trying to use isn't supported")
     }
-
-    let reward_value = get_reward_by_type(&env, &reward_type);
-    transfer(&env, &approval_sign, &get_account_identifier(to.clone()), &BigInt::from_u32(&env, reward_value));
 }
 
 fn transfer(env: &Env, approval_sign: &Signature, to: &Identifier, amount: &BigInt) {
@@ -110,6 +708,28 @@ fn transfer(env: &Env, approval_sign: &Signature, to: &Identifier, amount: &BigI
     client.xfer(&approval_sign, &nonce, &to, &amount);
 }
 
+// Moves tokens out of the admin's balance using the allowance granted to this contract.
+fn transfer_from_treasury(env: &Env, to: &Identifier, amount: &BigInt) {
+    let tc_id = get_token_contract_id(&env);
+    let client = token::Client::new(&env, tc_id);
+
+    client.xfer_from(
+        &Signature::Invoker,
+        &BigInt::zero(&env),
+        &get_admin_id(&env),
+        &to,
+        &amount
+    );
+}
+
+// Moves tokens held by this contract, like the ones escrowed for the streams.
+fn transfer_from_contract(env: &Env, to: &Identifier, amount: &BigInt) {
+    let tc_id = get_token_contract_id(&env);
+    let client = token::Client::new(&env, tc_id);
+
+    client.xfer(&Signature::Invoker, &BigInt::zero(&env), &to, &amount);
+}
+
 fn get_contract_balance(env: &Env) -> BigInt {
     let tc_id = get_token_contract_id(&env);
     let client = token::Client::new(&env, tc_id);
@@ -159,40 +779,1290 @@ fn get_reward_by_type(env: &Env, r_type: &Symbol) -> u32 {
     rewards.get(r_type.clone()).unwrap().unwrap()
 }
 
-// ADMIN
-fn get_admin_id(env: &Env) -> Identifier {
-    let key = DataKey::AdminId;
-    env.data().get(key).unwrap().unwrap()
+// KUDOS
+fn set_kudos_config(env: &Env, config: &KudosConfig) {
+    if config.period == 0 {
+        panic!("The kudos period must be greater than zero");
+    }
+
+    env.data().set(DataKey::KudosCfg, config);
 }
 
-fn set_admin_id(env: &Env, account_id: &Identifier) {
-    env.data().set(DataKey::AdminId, account_id);
+fn get_kudos_config(env: &Env) -> KudosConfig {
+    let key = DataKey::KudosCfg;
+    env.data()
+        .get(key)
+        .unwrap_or(Ok(KudosConfig { budget: 0, period: 1 }))
+        .unwrap()
 }
 
-// TOKEN CONTRACT
-fn get_token_contract_id(env: &Env) -> BytesN<32> {
-    let key = DataKey::TokenId;
-    env.data().get(key).unwrap().unwrap()
+fn get_current_kudos_period(env: &Env) -> u64 {
+    env.ledger().timestamp() / get_kudos_config(&env).period
 }
 
-fn set_token_id(e: &Env, token_id: &BytesN<32>) {
-    e.data().set(DataKey::TokenId, token_id);
+fn get_kudos_spent(env: &Env, account: &AccountId) -> u32 {
+    let key = DataKey::KudosUsed(account.clone());
+    let usage: KudosUsage = env.data()
+        .get(key)
+        .unwrap_or(Ok(KudosUsage { period: 0, spent: 0 }))
+        .unwrap();
+
+    // The budget is refreshed at the start of every period
+    if usage.period != get_current_kudos_period(&env) {
+        return 0;
+    }
+
+    usage.spent
 }
 
-// IDENTIFIER WRAPPERS
-pub fn get_account_identifier(account_id: AccountId) -> Identifier {
-    Identifier::Account(account_id)
+fn get_kudos_left(env: &Env, account: &AccountId) -> u32 {
+    let budget = get_kudos_config(&env).budget;
+    let spent = get_kudos_spent(&env, &account);
+
+    if spent >= budget {
+        return 0;
+    }
+
+    budget - spent
 }
 
-pub fn get_contract_identifier(contract_id: BytesN<32>) -> Identifier {
-    Identifier::Contract(contract_id)
+fn get_kudos_received(env: &Env, account: &AccountId) -> u32 {
+    let key = DataKey::KudosRecv(account.clone());
+    env.data().get(key).unwrap_or(Ok(0)).unwrap()
 }
 
-pub struct OrganizationContract;
+fn send_kudos(env: &Env, from: &AccountId, to: &AccountId, amount: u32, reason: &Symbol) {
+    check_not_paused(&env);
 
-pub trait OrganizationContractTrait {
-    fn initialize(
-        e: Env,
+    if !is_member(&env, &from) || is_alumni(&env, &from) {
+        panic!("Only members of the organization can send kudos");
+    }
+
+    if is_suspended(&env, &from) {
+        panic!("Suspended members can't send kudos");
+    }
+
+    if !is_member(&env, &to) {
+        panic!("The user account you're trying to send kudos to doesn't belong to the organization");
+    }
+
+    if is_suspended(&env, &to) {
+        panic!("The user account you're trying to send kudos to is suspended");
+    }
+
+    if is_alumni(&env, &to) {
+        panic!("The user account you're trying to send kudos to is an alumni member");
+    }
+
+    if from == to {
+        panic!("Members can't send kudos to themselves");
+    }
+
+    if amount > get_kudos_left(&env, &from) {
+        panic!("Not enough kudos budget left for this period");
+    }
+
+    env.data().set(
+        DataKey::KudosUsed(from.clone()),
+        KudosUsage {
+            period: get_current_kudos_period(&env),
+            spent: get_kudos_spent(&env, &from) + amount
+        }
+    );
+    env.data().set(DataKey::KudosRecv(to.clone()), get_kudos_received(&env, &to) + amount);
+
+    transfer_from_treasury(&env, &get_payout_identifier(&env, &to), &BigInt::from_u32(&env, amount));
+
+    env.events().publish((symbol!("kudos"), from.clone(), to.clone()), (amount, reason.clone()));
+}
+
+// TIERS
+fn set_tiers(env: &Env, tiers: &Vec<Tier>) {
+    let mut previous_threshold: u64 = 0;
+
+    for (index, tier) in tiers.iter().enumerate() {
+        let tier = tier.unwrap();
+
+        if index == 0 && tier.threshold != 0 {
+            panic!("The first tier must start at a threshold of zero");
+        }
+
+        if index > 0 && tier.threshold <= previous_threshold {
+            panic!("The tier thresholds must be in ascending order");
+        }
+
+        previous_threshold = tier.threshold;
+    }
+
+    env.data().set(DataKey::Tiers, tiers);
+}
+
+fn get_tiers(env: &Env) -> Vec<Tier> {
+    let key = DataKey::Tiers;
+    env.data()
+        .get(key)
+        .unwrap_or(Ok(vec![&env]))
+        .unwrap()
+}
+
+fn get_member_tier(env: &Env, account: &AccountId) -> u32 {
+    let key = DataKey::MTier(account.clone());
+    env.data().get(key).unwrap_or(Ok(0)).unwrap()
+}
+
+fn set_member_tier(env: &Env, account: &AccountId, tier: u32) {
+    if tier >= get_tiers(&env).len() {
+        panic!("The tier you are trying to assign doesn't exist");
+    }
+
+    let previous_tier = get_member_tier(&env, &account);
+    env.data().set(DataKey::MTier(account.clone()), tier);
+
+    if previous_tier != tier {
+        env.events().publish((symbol!("tier"), account.clone()), (previous_tier, tier));
+    }
+}
+
+fn get_tier_multiplier(env: &Env, account: &AccountId) -> u32 {
+    match get_tiers(&env).get(get_member_tier(&env, &account)) {
+        Some(tier) => tier.unwrap().multiplier,
+        None => 100,
+    }
+}
+
+fn get_member_reward_value(env: &Env, account: &AccountId, reward_type: &Symbol) -> u32 {
+    let base_value = get_reward_by_type(&env, &reward_type) as u64;
+    let multiplier = get_tier_multiplier(&env, &account) as u64;
+
+    match u32::try_from(base_value * multiplier / 100) {
+        Ok(value) => value,
+        Err(_) => panic!("The reward value is too large"),
+    }
+}
+
+fn get_earned(env: &Env, account: &AccountId) -> u64 {
+    let key = DataKey::Earned(account.clone());
+    env.data().get(key).unwrap_or(Ok(0)).unwrap()
+}
+
+fn add_earned(env: &Env, account: &AccountId, amount: u32) {
+    let earned = get_earned(&env, &account) + amount as u64;
+    env.data().set(DataKey::Earned(account.clone()), earned);
+}
+
+// Promotions are automatic, members never get demoted by this check.
+fn promote_member(env: &Env, account: &AccountId) {
+    let earned = get_earned(&env, &account);
+    let current_tier = get_member_tier(&env, &account);
+
+    let mut reached_tier = current_tier;
+    for (index, tier) in get_tiers(&env).iter().enumerate() {
+        if earned >= tier.unwrap().threshold && index as u32 > reached_tier {
+            reached_tier = index as u32;
+        }
+    }
+
+    if reached_tier != current_tier {
+        set_member_tier(&env, &account, reached_tier);
+    }
+}
+
+// REPUTATION
+fn set_reputation_decay(env: &Env, decay: &RepDecay) {
+    if decay.rate > 100 {
+        panic!("The reputation decay rate can't be greater than 100");
+    }
+
+    if decay.period == 0 {
+        panic!("The reputation decay period must be greater than zero");
+    }
+
+    env.data().set(DataKey::RepDecay, decay);
+}
+
+fn get_reputation_decay(env: &Env) -> RepDecay {
+    let key = DataKey::RepDecay;
+    env.data()
+        .get(key)
+        .unwrap_or(Ok(RepDecay { rate: 0, period: 1 }))
+        .unwrap()
+}
+
+// Returns the reputation with the decay of every period elapsed since its last update applied.
+fn get_decayed_reputation(env: &Env, account: &AccountId) -> Reputation {
+    let now = env.ledger().timestamp();
+    let key = DataKey::Rep(account.clone());
+    let mut reputation: Reputation = env.data()
+        .get(key)
+        .unwrap_or(Ok(Reputation { points: 0, updated: now }))
+        .unwrap();
+
+    let decay = get_reputation_decay(&env);
+    if decay.rate == 0 {
+        reputation.updated = now;
+        return reputation;
+    }
+
+    let periods = (now - reputation.updated) / decay.period;
+    reputation.points = apply_decay(reputation.points, &decay, periods);
+
+    // Keep the time already elapsed on the current period
+    reputation.updated += periods * decay.period;
+    reputation
+}
+
+fn apply_decay(points: u64, decay: &RepDecay, periods: u64) -> u64 {
+    let mut points = points;
+
+    for _ in 0..periods {
+        if points == 0 {
+            break;
+        }
+
+        points = points * (100 - decay.rate) as u64 / 100;
+    }
+
+    points
+}
+
+// The points a reward still adds to the reputation, after the periods elapsed since it was given.
+fn get_decayed_reward_points(env: &Env, reward: &Reward) -> u64 {
+    let decay = get_reputation_decay(&env);
+    if decay.rate == 0 {
+        return reward.points;
+    }
+
+    let periods = (env.ledger().timestamp() - reward.time) / decay.period;
+    apply_decay(reward.points, &decay, periods)
+}
+
+fn get_reputation(env: &Env, account: &AccountId) -> u64 {
+    get_decayed_reputation(&env, &account).points
+}
+
+fn add_reputation(env: &Env, account: &AccountId, points: u64) {
+    let mut reputation = get_decayed_reputation(&env, &account);
+    reputation.points += points;
+
+    env.data().set(DataKey::Rep(account.clone()), reputation);
+}
+
+fn get_reputation_ranking(env: &Env, limit: u32) -> Vec<RepEntry> {
+    let mut ranking: Vec<RepEntry> = vec![&env];
+
+    for account in get_members::<AccountId>(&env).iter() {
+        let account = account.unwrap();
+        let entry = RepEntry { points: get_reputation(&env, &account), account };
+
+        let mut position = ranking.len();
+        for (index, ranked) in ranking.iter().enumerate() {
+            if entry.points > ranked.unwrap().points {
+                position = index as u32;
+                break;
+            }
+        }
+
+        ranking.insert(position, entry);
+    }
+
+    while ranking.len() > limit {
+        ranking.pop_back();
+    }
+
+    ranking
+}
+
+// REWARD HISTORY
+fn get_reward_count(env: &Env, account: &AccountId, reward_type: &Symbol) -> u32 {
+    let key = DataKey::RwdCount(RewardKey { account: account.clone(), r_type: reward_type.clone() });
+    env.data().get(key).unwrap_or(Ok(0)).unwrap()
+}
+
+fn get_reward_total(env: &Env, account: &AccountId) -> u32 {
+    let key = DataKey::RwdTotal(account.clone());
+    env.data().get(key).unwrap_or(Ok(0)).unwrap()
+}
+
+fn add_reward_count(env: &Env, account: &AccountId, reward_type: &Symbol) {
+    let count = get_reward_count(&env, &account, &reward_type) + 1;
+    let total = get_reward_total(&env, &account) + 1;
+
+    env.data().set(DataKey::RwdCount(RewardKey { account: account.clone(), r_type: reward_type.clone() }), count);
+    env.data().set(DataKey::RwdTotal(account.clone()), total);
+}
+
+fn remove_reward_count(env: &Env, account: &AccountId, reward_type: &Symbol) {
+    let count = get_reward_count(&env, &account, &reward_type).saturating_sub(1);
+    let total = get_reward_total(&env, &account).saturating_sub(1);
+
+    env.data().set(DataKey::RwdCount(RewardKey { account: account.clone(), r_type: reward_type.clone() }), count);
+    env.data().set(DataKey::RwdTotal(account.clone()), total);
+}
+
+// DISPUTES
+fn get_dispute_window(env: &Env) -> u64 {
+    let key = DataKey::DisputeW;
+    env.data().get(key).unwrap_or(Ok(0)).unwrap()
+}
+
+fn set_dispute_window(env: &Env, window: u64) {
+    env.data().set(DataKey::DisputeW, window);
+}
+
+fn get_reward_record(env: &Env, id: u32) -> Reward {
+    let key = DataKey::Reward(id);
+
+    match env.data().get(key) {
+        Some(reward) => reward.unwrap(),
+        None => panic!("The reward doesn't exist"),
+    }
+}
+
+fn get_member_rewards(env: &Env, account: &AccountId) -> Vec<u32> {
+    let key = DataKey::MRewards(account.clone());
+    env.data()
+        .get(key)
+        .unwrap_or(Ok(vec![&env]))
+        .unwrap()
+}
+
+// Keeps every reward paid to a member so it can be disputed within the window.
+fn record_reward(env: &Env, to: &AccountId, source: &RewardSrc, reward_type: &Symbol, amount: u32) -> u32 {
+    let id: u32 = env.data().get(DataKey::RewardId).unwrap_or(Ok(0)).unwrap();
+    let now = env.ledger().timestamp();
+
+    env.data().set(DataKey::Reward(id), Reward {
+        member: to.clone(),
+        source: source.clone(),
+        r_type: reward_type.clone(),
+        amount,
+        points: get_reward_by_type(&env, &reward_type) as u64,
+        payout: get_member(&env, &to).payout,
+        time: now,
+        until: now.saturating_add(get_dispute_window(&env)),
+        reversed: false
+    });
+    env.data().set(DataKey::RewardId, id + 1);
+
+    let mut rewards = get_member_rewards(&env, &to);
+    rewards.push_back(id);
+    env.data().set(DataKey::MRewards(to.clone()), rewards);
+
+    env.events().publish((symbol!("rewarded"), to.clone()), (id, amount));
+
+    id
+}
+
+// The tokens are pulled back from the payout address through the allowance it granted to this
+// contract. Tiers aren't recalculated, members never get demoted by a reversal.
+fn reverse_reward(env: &Env, id: u32) {
+    check_not_paused(&env);
+
+    let mut reward = get_reward_record(&env, id);
+
+    if reward.reversed {
+        panic!("The reward was already reversed");
+    }
+
+    if env.ledger().timestamp() >= reward.until {
+        panic!("The dispute window for this reward is over");
+    }
+
+    if !is_member(&env, &reward.member) {
+        panic!("The rewarded account doesn't belong to the organization anymore");
+    }
+
+    reward.reversed = true;
+    env.data().set(DataKey::Reward(id), reward.clone());
+
+    let earned = get_earned(&env, &reward.member).saturating_sub(reward.amount as u64);
+    env.data().set(DataKey::Earned(reward.member.clone()), earned);
+
+    let mut reputation = get_decayed_reputation(&env, &reward.member);
+    reputation.points = reputation.points.saturating_sub(get_decayed_reward_points(&env, &reward));
+    env.data().set(DataKey::Rep(reward.member.clone()), reputation);
+
+    remove_reward_count(&env, &reward.member, &reward.r_type);
+
+    if let RewardSrc::Team(name) = &reward.source {
+        refund_team_spend(&env, &name, reward.amount, reward.time);
+    }
+
+    let tc_id = get_token_contract_id(&env);
+    let client = token::Client::new(&env, tc_id);
+
+    client.xfer_from(
+        &Signature::Invoker,
+        &BigInt::zero(&env),
+        &get_account_identifier(reward.payout.clone()),
+        &get_admin_id(&env),
+        &BigInt::from_u32(&env, reward.amount)
+    );
+
+    env.events().publish((symbol!("reversed"), reward.member), (id, reward.amount));
+}
+
+// BADGES
+fn get_badge_catalog(env: &Env) -> Map<Symbol, BadgeRule> {
+    let key = DataKey::Badges;
+    env.data()
+        .get(key)
+        .unwrap_or(Ok(Map::new(&env)))
+        .unwrap()
+}
+
+fn set_badge(env: &Env, badge: &Symbol, rule: &BadgeRule) {
+    let mut catalog = get_badge_catalog(&env);
+    catalog.set(badge.clone(), rule.clone());
+
+    env.data().set(DataKey::Badges, catalog);
+}
+
+fn get_member_badges(env: &Env, account: &AccountId) -> Vec<Symbol> {
+    let key = DataKey::MBadges(account.clone());
+    env.data()
+        .get(key)
+        .unwrap_or(Ok(vec![&env]))
+        .unwrap()
+}
+
+fn get_badge_holders(env: &Env, badge: &Symbol) -> Vec<AccountId> {
+    let key = DataKey::Holders(badge.clone());
+    env.data()
+        .get(key)
+        .unwrap_or(Ok(vec![&env]))
+        .unwrap()
+}
+
+fn award_badge(env: &Env, account: &AccountId, badge: &Symbol) {
+    if !get_badge_catalog(&env).contains_key(badge.clone()) {
+        panic!("The badge you are trying to award isn't in the catalog");
+    }
+
+    let mut badges = get_member_badges(&env, &account);
+    if badges.contains(badge) {
+        return;
+    }
+
+    badges.push_back(badge.clone());
+    env.data().set(DataKey::MBadges(account.clone()), badges);
+
+    let mut holders = get_badge_holders(&env, &badge);
+    holders.push_back(account.clone());
+    env.data().set(DataKey::Holders(badge.clone()), holders);
+
+    env.events().publish((symbol!("badge"), account.clone()), badge.clone());
+}
+
+fn is_badge_earned(env: &Env, account: &AccountId, rule: &BadgeRule) -> bool {
+    match rule {
+        BadgeRule::Manual => false,
+        BadgeRule::FirstRwd => get_reward_total(&env, &account) > 0,
+        BadgeRule::RwdCount(reward_count) => {
+            get_reward_count(&env, &account, &reward_count.r_type) >= reward_count.count
+        },
+        BadgeRule::Tenure(seconds) => {
+            env.ledger().timestamp() >= get_member(&env, &account).joined.saturating_add(*seconds)
+        },
+    }
+}
+
+fn check_badges(env: &Env, account: &AccountId) {
+    for entry in get_badge_catalog(&env).iter() {
+        let (badge, rule) = entry.unwrap();
+
+        if is_badge_earned(&env, &account, &rule) {
+            award_badge(&env, &account, &badge);
+        }
+    }
+}
+
+fn remove_member_badges(env: &Env, account: &AccountId) {
+    for badge in get_member_badges(&env, &account).iter() {
+        let badge = badge.unwrap();
+        let mut holders = get_badge_holders(&env, &badge);
+
+        if let Some(index) = holders.first_index_of(account) {
+            holders.remove(index);
+            env.data().set(DataKey::Holders(badge), holders);
+        }
+    }
+
+    env.data().remove(DataKey::MBadges(account.clone()));
+}
+
+// SCHEMA VERSION
+fn get_schema_version(env: &Env) -> u32 {
+    let key = DataKey::SchemaV;
+    env.data().get(key).unwrap().unwrap()
+}
+
+fn set_schema_version(env: &Env, version: u32) {
+    env.data().set(DataKey::SchemaV, version);
+}
+
+// TEAMS
+fn get_teams(env: &Env) -> Vec<Symbol> {
+    let key = DataKey::Teams;
+    env.data()
+        .get(key)
+        .unwrap_or(Ok(vec![&env]))
+        .unwrap()
+}
+
+fn get_team(env: &Env, name: &Symbol) -> Team {
+    let key = DataKey::Team(name.clone());
+
+    match env.data().get(key) {
+        Some(team) => team.unwrap(),
+        None => panic!("The team doesn't exist"),
+    }
+}
+
+fn set_team(env: &Env, name: &Symbol, team: &Team) {
+    if team.period == 0 {
+        panic!("The team budget period must be greater than zero");
+    }
+
+    if !is_member(&env, &team.manager) {
+        panic!("The team manager must belong to the organization");
+    }
+
+    let mut teams = get_teams(&env);
+    if !teams.contains(name) {
+        teams.push_back(name.clone());
+        env.data().set(DataKey::Teams, teams);
+    }
+
+    env.data().set(DataKey::Team(name.clone()), team);
+}
+
+fn add_team_member(env: &Env, name: &Symbol, account: &AccountId) {
+    if !is_member(&env, &account) {
+        panic!("Team members must belong to the organization");
+    }
+
+    let mut team = get_team(&env, &name);
+    if team.members.contains(account) {
+        panic!("The account already belongs to the team");
+    }
+
+    team.members.push_back(account.clone());
+    env.data().set(DataKey::Team(name.clone()), team);
+}
+
+fn remove_team_member(env: &Env, name: &Symbol, account: &AccountId) {
+    let mut team = get_team(&env, &name);
+
+    match team.members.first_index_of(account) {
+        Some(index) => team.members.remove(index),
+        None => panic!("The account doesn't belong to the team"),
+    };
+
+    env.data().set(DataKey::Team(name.clone()), team);
+}
+
+fn remove_from_teams(env: &Env, account: &AccountId) {
+    for name in get_teams(&env).iter() {
+        let name = name.unwrap();
+
+        if get_team(&env, &name).members.contains(account) {
+            remove_team_member(&env, &name, &account);
+        }
+    }
+}
+
+// The spent amount is reset at the start of every period, the total keeps growing.
+fn get_team_spend(env: &Env, name: &Symbol) -> TeamSpend {
+    let current_period = env.ledger().timestamp() / get_team(&env, &name).period;
+    let key = DataKey::TeamSpent(name.clone());
+
+    let mut spend: TeamSpend = env.data()
+        .get(key)
+        .unwrap_or(Ok(TeamSpend { period: current_period, spent: 0, total: 0 }))
+        .unwrap();
+
+    if spend.period != current_period {
+        spend.period = current_period;
+        spend.spent = 0;
+    }
+
+    spend
+}
+
+// The budget of the current period is only refunded for rewards given during that period.
+fn refund_team_spend(env: &Env, name: &Symbol, amount: u32, time: u64) {
+    let mut spend = get_team_spend(&env, &name);
+
+    if time / get_team(&env, &name).period == spend.period {
+        spend.spent = spend.spent.saturating_sub(amount);
+    }
+
+    spend.total = spend.total.saturating_sub(amount as u64);
+    env.data().set(DataKey::TeamSpent(name.clone()), spend);
+}
+
+fn get_team_budget_left(env: &Env, name: &Symbol) -> u32 {
+    let budget = get_team(&env, &name).budget;
+    let spent = get_team_spend(&env, &name).spent;
+
+    if spent >= budget {
+        return 0;
+    }
+
+    budget - spent
+}
+
+fn reward_team_member(env: &Env, manager: &AccountId, name: &Symbol, to: &AccountId, reward_type: &Symbol) -> u32 {
+    check_not_paused(&env);
+
+    let team = get_team(&env, &name);
+
+    if &team.manager != manager || !is_member(&env, &manager) {
+        panic!("Only the team manager can reward the team members");
+    }
+
+    if is_suspended(&env, &manager) || is_alumni(&env, &manager) {
+        panic!("Only active team managers can reward the team members");
+    }
+
+    if !team.members.contains(to) {
+        panic!("The user account you're trying to reward doesn't belong to the team");
+    }
+
+    let reward_value = credit_reward(&env, &to, &reward_type);
+
+    if reward_value > get_team_budget_left(&env, &name) {
+        panic!("Not enough team budget left for this period");
+    }
+
+    let mut spend = get_team_spend(&env, &name);
+    spend.spent += reward_value;
+    spend.total += reward_value as u64;
+    env.data().set(DataKey::TeamSpent(name.clone()), spend);
+
+    transfer_from_treasury(&env, &get_payout_identifier(&env, &to), &BigInt::from_u32(&env, reward_value));
+
+    record_reward(&env, &to, &RewardSrc::Team(name.clone()), &reward_type, reward_value)
+}
+
+// STREAMS
+fn get_stream(env: &Env, id: u32) -> Stream {
+    let key = DataKey::Stream(id);
+
+    match env.data().get(key) {
+        Some(stream) => stream.unwrap(),
+        None => panic!("The stream doesn't exist"),
+    }
+}
+
+fn set_stream(env: &Env, id: u32, stream: &Stream) {
+    env.data().set(DataKey::Stream(id), stream);
+}
+
+fn get_member_streams(env: &Env, account: &AccountId) -> Vec<u32> {
+    let key = DataKey::MStreams(account.clone());
+    env.data()
+        .get(key)
+        .unwrap_or(Ok(vec![&env]))
+        .unwrap()
+}
+
+fn open_stream(env: &Env, member: &AccountId, rate: u32, start: u64, end: u64) -> u32 {
+    check_not_paused(&env);
+
+    if !is_member(&env, &member) {
+        panic!("The user account you're trying to stream to doesn't belong to the organization");
+    }
+
+    if is_alumni(&env, &member) {
+        panic!("The user account you're trying to stream to is an alumni member");
+    }
+
+    if start >= end {
+        panic!("The stream must end after it starts");
+    }
+
+    let id: u32 = env.data().get(DataKey::StreamId).unwrap_or(Ok(0)).unwrap();
+    let total = rate as u64 * (end - start);
+
+    // The whole stream is escrowed by this contract when it's opened
+    transfer_from_treasury(&env, &get_contract_identifier(env.current_contract()), &BigInt::from_u64(&env, total));
+
+    set_stream(&env, id, &Stream {
+        member: member.clone(),
+        rate,
+        start,
+        end,
+        total,
+        withdrawn: 0,
+        active: true
+    });
+    env.data().set(DataKey::StreamId, id + 1);
+
+    let mut streams = get_member_streams(&env, &member);
+    streams.push_back(id);
+    env.data().set(DataKey::MStreams(member.clone()), streams);
+
+    env.events().publish((symbol!("stream"), member.clone()), (id, rate, start, end));
+
+    id
+}
+
+fn get_streamed_amount(env: &Env, stream: &Stream) -> u64 {
+    let now = core::cmp::min(env.ledger().timestamp(), stream.end);
+
+    if now <= stream.start {
+        return 0;
+    }
+
+    stream.rate as u64 * (now - stream.start)
+}
+
+fn get_withdrawable_amount(env: &Env, stream: &Stream) -> u64 {
+    if !stream.active {
+        return 0;
+    }
+
+    get_streamed_amount(&env, &stream) - stream.withdrawn
+}
+
+fn withdraw_stream(env: &Env, member: &AccountId, id: u32) {
+    check_not_paused(&env);
+
+    let mut stream = get_stream(&env, id);
+
+    if &stream.member != member {
+        panic!("Only the stream's member can withdraw from it");
+    }
+
+    let amount = get_withdrawable_amount(&env, &stream);
+    stream.withdrawn += amount;
+    set_stream(&env, id, &stream);
+
+    transfer_from_contract(&env, &get_payout_identifier(&env, &member), &BigInt::from_u64(&env, amount));
+}
+
+// Pays the member what was already streamed and returns the remainder to the treasury.
+fn cancel_stream(env: &Env, id: u32) {
+    check_not_paused(&env);
+
+    let stream = get_stream(&env, id);
+
+    if !stream.active {
+        panic!("The stream was already closed");
+    }
+
+    close_stream(&env, id, stream);
+}
+
+fn close_stream(env: &Env, id: u32, mut stream: Stream) {
+    let streamed = get_streamed_amount(&env, &stream);
+    let owed = streamed - stream.withdrawn;
+
+    stream.withdrawn = streamed;
+    stream.active = false;
+    set_stream(&env, id, &stream);
+
+    transfer_from_contract(&env, &get_payout_identifier(&env, &stream.member), &BigInt::from_u64(&env, owed));
+    transfer_from_contract(&env, &get_admin_id(&env), &BigInt::from_u64(&env, stream.total - streamed));
+
+    env.events().publish((symbol!("s_cancel"), stream.member), id);
+}
+
+fn close_member_streams(env: &Env, account: &AccountId) {
+    for id in get_member_streams(&env, &account).iter() {
+        let id = id.unwrap();
+        let stream = get_stream(&env, id);
+
+        if stream.active {
+            close_stream(&env, id, stream);
+        }
+    }
+
+    env.data().remove(DataKey::MStreams(account.clone()));
+}
+
+// EPOCHS
+fn get_epoch(env: &Env, id: u32) -> Epoch {
+    let key = DataKey::Epoch(id);
+
+    match env.data().get(key) {
+        Some(epoch) => epoch.unwrap(),
+        None => panic!("The epoch doesn't exist"),
+    }
+}
+
+fn set_epoch(env: &Env, id: u32, epoch: &Epoch) {
+    env.data().set(DataKey::Epoch(id), epoch);
+}
+
+fn get_next_epoch_id(env: &Env) -> u32 {
+    let key = DataKey::EpochId;
+    env.data().get(key).unwrap_or(Ok(0)).unwrap()
+}
+
+fn get_epoch_points(env: &Env, id: u32, account: &AccountId) -> u64 {
+    let key = DataKey::EpPoints(EpochKey { epoch: id, account: account.clone() });
+    env.data().get(key).unwrap_or(Ok(0)).unwrap()
+}
+
+// Epochs run one at a time, the previous one must be finalized before opening a new one.
+fn open_epoch(env: &Env, pool: u64, end: u64, claim_end: u64) -> u32 {
+    check_not_paused(&env);
+
+    let id = get_next_epoch_id(&env);
+
+    if id > 0 && !get_epoch(&env, id - 1).finalized {
+        panic!("The current epoch must be finalized before opening a new one");
+    }
+
+    if end <= env.ledger().timestamp() {
+        panic!("The epoch must end in the future");
+    }
+
+    if claim_end <= end {
+        panic!("The claim deadline must be after the epoch ends");
+    }
+
+    transfer_from_treasury(&env, &get_contract_identifier(env.current_contract()), &BigInt::from_u64(&env, pool));
+
+    set_epoch(&env, id, &Epoch { pool, end, claim_end, points: 0, claimed: 0, finalized: false, swept: false });
+    env.data().set(DataKey::EpochId, id + 1);
+
+    env.events().publish((symbol!("epoch"), symbol!("opened")), (id, pool, end));
+
+    id
+}
+
+fn accrue_points(env: &Env, to: &AccountId, reward_type: &Symbol) {
+    let id = get_next_epoch_id(&env);
+
+    if id == 0 {
+        panic!("There isn't an epoch running");
+    }
+
+    let id = id - 1;
+    let mut epoch = get_epoch(&env, id);
+
+    if epoch.finalized || env.ledger().timestamp() >= epoch.end {
+        panic!("There isn't an epoch running");
+    }
+
+    check_rewardable(&env, &to, &reward_type);
+
+    let points = get_member_reward_value(&env, &to, &reward_type) as u64;
+
+    epoch.points += points;
+    set_epoch(&env, id, &epoch);
+
+    let key = DataKey::EpPoints(EpochKey { epoch: id, account: to.clone() });
+    env.data().set(key, get_epoch_points(&env, id, &to) + points);
+}
+
+fn finalize_epoch(env: &Env, id: u32) {
+    check_not_paused(&env);
+
+    let mut epoch = get_epoch(&env, id);
+
+    if epoch.finalized {
+        panic!("The epoch was already finalized");
+    }
+
+    if env.ledger().timestamp() < epoch.end {
+        panic!("The epoch hasn't ended yet");
+    }
+
+    // Nobody can claim an epoch without points, so its pool goes back to the treasury
+    epoch.finalized = true;
+    epoch.swept = epoch.points == 0;
+    set_epoch(&env, id, &epoch);
+
+    if epoch.points == 0 {
+        transfer_from_contract(&env, &get_admin_id(&env), &BigInt::from_u64(&env, epoch.pool));
+    }
+
+    env.events().publish((symbol!("epoch"), symbol!("final")), (id, epoch.points));
+}
+
+fn get_epoch_share(env: &Env, id: u32, account: &AccountId) -> u64 {
+    let epoch = get_epoch(&env, id);
+
+    if epoch.points == 0 {
+        return 0;
+    }
+
+    let points = get_epoch_points(&env, id, &account);
+    (epoch.pool as u128 * points as u128 / epoch.points as u128) as u64
+}
+
+fn claim_epoch_share(env: &Env, account: &AccountId, id: u32) {
+    check_not_paused(&env);
+
+    let mut epoch = get_epoch(&env, id);
+
+    if !epoch.finalized {
+        panic!("The epoch must be finalized before claiming");
+    }
+
+    if env.ledger().timestamp() >= epoch.claim_end {
+        panic!("The claim deadline of this epoch is over");
+    }
+
+    let key = DataKey::EpClaimed(EpochKey { epoch: id, account: account.clone() });
+    if env.data().has(key.clone()) {
+        panic!("The epoch share was already claimed");
+    }
+
+    let share = get_epoch_share(&env, id, &account);
+    if share == 0 {
+        panic!("There is no epoch share to claim");
+    }
+
+    env.data().set(key, true);
+
+    epoch.claimed += share;
+    set_epoch(&env, id, &epoch);
+
+    transfer_from_contract(&env, &get_payout_identifier(&env, &account), &BigInt::from_u64(&env, share));
+}
+
+// Once the claim deadline is over, the shares nobody claimed and the rounding dust return to the treasury.
+fn sweep_epoch(env: &Env, id: u32) {
+    check_not_paused(&env);
+
+    let mut epoch = get_epoch(&env, id);
+
+    if !epoch.finalized {
+        panic!("The epoch must be finalized before sweeping");
+    }
+
+    if env.ledger().timestamp() < epoch.claim_end {
+        panic!("The claim deadline of this epoch isn't over yet");
+    }
+
+    if epoch.swept {
+        panic!("The epoch was already swept");
+    }
+
+    let unclaimed = epoch.pool - epoch.claimed;
+
+    epoch.swept = true;
+    set_epoch(&env, id, &epoch);
+
+    transfer_from_contract(&env, &get_admin_id(&env), &BigInt::from_u64(&env, unclaimed));
+
+    env.events().publish((symbol!("epoch"), symbol!("swept")), (id, unclaimed));
+}
+
+// GOVERNANCE
+fn get_gov_config(env: &Env) -> GovConfig {
+    let key = DataKey::GovCfg;
+
+    match env.data().get(key) {
+        Some(config) => config.unwrap(),
+        None => panic!("The organization's governance isn't configured"),
+    }
+}
+
+fn set_gov_config(env: &Env, config: &GovConfig) {
+    if config.period == 0 {
+        panic!("The voting period must be greater than zero");
+    }
+
+    env.data().set(DataKey::GovCfg, config);
+}
+
+fn get_proposal(env: &Env, id: u32) -> Proposal {
+    let key = DataKey::Proposal(id);
+
+    match env.data().get(key) {
+        Some(proposal) => proposal.unwrap(),
+        None => panic!("The proposal doesn't exist"),
+    }
+}
+
+fn check_voting_member(env: &Env, account: &AccountId) {
+    if !is_member(&env, &account) || is_suspended(&env, &account) || is_alumni(&env, &account) {
+        panic!("Only active members of the organization can take part in governance");
+    }
+}
+
+fn create_proposal(env: &Env, proposer: &AccountId, action: &Action) -> u32 {
+    check_voting_member(&env, &proposer);
+
+    let config = get_gov_config(&env);
+    let id: u32 = env.data().get(DataKey::PropId).unwrap_or(Ok(0)).unwrap();
+
+    env.data().set(DataKey::Proposal(id), Proposal {
+        proposer: proposer.clone(),
+        action: action.clone(),
+        votes_for: 0,
+        against: 0,
+        end: env.ledger().timestamp() + config.period,
+        executed: false
+    });
+    env.data().set(DataKey::PropId, id + 1);
+
+    env.events().publish((symbol!("proposal"), proposer.clone()), id);
+
+    id
+}
+
+fn get_vote_weight(env: &Env, account: &AccountId) -> u64 {
+    match get_gov_config(&env).weight {
+        VoteWeight::Member => 1,
+        VoteWeight::Reputation => get_reputation(&env, &account),
+    }
+}
+
+fn cast_vote(env: &Env, voter: &AccountId, id: u32, support: bool) {
+    check_voting_member(&env, &voter);
+
+    let mut proposal = get_proposal(&env, id);
+
+    if env.ledger().timestamp() >= proposal.end {
+        panic!("The voting period for this proposal is over");
+    }
+
+    let key = DataKey::Voted(VoteKey { proposal: id, account: voter.clone() });
+    if env.data().has(key.clone()) {
+        panic!("The account already voted on this proposal");
+    }
+
+    let weight = get_vote_weight(&env, &voter);
+
+    if support {
+        proposal.votes_for += weight;
+    } else {
+        proposal.against += weight;
+    }
+
+    env.data().set(key, support);
+    env.data().set(DataKey::Proposal(id), proposal);
+
+    env.events().publish((symbol!("vote"), voter.clone()), (id, support, weight));
+}
+
+fn is_proposal_passed(env: &Env, proposal: &Proposal) -> bool {
+    let quorum = get_gov_config(&env).quorum;
+
+    proposal.votes_for + proposal.against >= quorum && proposal.votes_for > proposal.against
+}
+
+// Passed proposals go through the same setters the admin uses.
+fn execute_proposal(env: &Env, id: u32) {
+    let mut proposal = get_proposal(&env, id);
+
+    if proposal.executed {
+        panic!("The proposal was already executed");
+    }
+
+    if env.ledger().timestamp() < proposal.end + get_gov_config(&env).delay {
+        panic!("The proposal can't be executed yet");
+    }
+
+    if !is_proposal_passed(&env, &proposal) {
+        panic!("The proposal didn't pass");
+    }
+
+    proposal.executed = true;
+    env.data().set(DataKey::Proposal(id), proposal.clone());
+
+    match proposal.action {
+        Action::SetReward(reward) => {
+            let mut rewards = get_rewards(&env);
+            rewards.set(reward.r_type, reward.value);
+            set_rewards(&env, &rewards);
+        },
+        Action::RmReward(reward_type) => {
+            let mut rewards = get_rewards(&env);
+            rewards.remove(reward_type);
+            set_rewards(&env, &rewards);
+        },
+        Action::SetAllowed(amount) => set_allowed_funds_to_issue(&env, amount),
+        Action::AddMember(account) => add_member(&env, account),
+    }
+
+    env.events().publish((symbol!("executed"),), id);
+}
+
+// BOUNTIES
+fn get_bounty(env: &Env, id: u32) -> Bounty {
+    let key = DataKey::Bounty(id);
+
+    match env.data().get(key) {
+        Some(bounty) => bounty.unwrap(),
+        None => panic!("The bounty doesn't exist"),
+    }
+}
+
+fn set_bounty(env: &Env, id: u32, bounty: &Bounty) {
+    env.data().set(DataKey::Bounty(id), bounty);
+}
+
+fn post_bounty(env: &Env, desc: &BytesN<32>, amount: u64, deadline: u64, reviewer: &AccountId) -> u32 {
+    check_not_paused(&env);
+
+    if deadline <= env.ledger().timestamp() {
+        panic!("The bounty deadline must be in the future");
+    }
+
+    let id: u32 = env.data().get(DataKey::BountyId).unwrap_or(Ok(0)).unwrap();
+
+    transfer_from_treasury(&env, &get_contract_identifier(env.current_contract()), &BigInt::from_u64(&env, amount));
+
+    set_bounty(&env, id, &Bounty {
+        desc: desc.clone(),
+        amount,
+        deadline,
+        reviewer: reviewer.clone(),
+        state: BountyState::Open
+    });
+    env.data().set(DataKey::BountyId, id + 1);
+
+    env.events().publish((symbol!("bounty"), symbol!("posted")), (id, amount, deadline));
+
+    id
+}
+
+fn claim_bounty(env: &Env, account: &AccountId, id: u32) {
+    if !is_member(&env, &account) || is_suspended(&env, &account) || is_alumni(&env, &account) {
+        panic!("Only active members of the organization can claim bounties");
+    }
+
+    let mut bounty = get_bounty(&env, id);
+
+    match bounty.state {
+        BountyState::Open => {},
+        _ => panic!("The bounty isn't open"),
+    }
+
+    if env.ledger().timestamp() >= bounty.deadline {
+        panic!("The bounty deadline is over");
+    }
+
+    bounty.state = BountyState::Claimed(account.clone());
+    set_bounty(&env, id, &bounty);
+
+    env.events().publish((symbol!("bounty"), symbol!("claimed")), (id, account.clone()));
+}
+
+fn submit_bounty(env: &Env, account: &AccountId, id: u32, work: &BytesN<32>) {
+    let mut bounty = get_bounty(&env, id);
+
+    match &bounty.state {
+        BountyState::Claimed(claimant) if claimant == account => {},
+        _ => panic!("Only the bounty's claimant can submit work"),
+    }
+
+    if env.ledger().timestamp() >= bounty.deadline {
+        panic!("The bounty deadline is over");
+    }
+
+    bounty.state = BountyState::Submitted(Submission { claimant: account.clone(), work: work.clone() });
+    set_bounty(&env, id, &bounty);
+
+    env.events().publish((symbol!("bounty"), symbol!("submitted")), (id, work.clone()));
+}
+
+fn release_bounty(env: &Env, reviewer: &AccountId, id: u32) {
+    check_not_paused(&env);
+
+    let mut bounty = get_bounty(&env, id);
+
+    if &bounty.reviewer != reviewer {
+        panic!("Only the bounty's reviewer can release it");
+    }
+
+    let claimant = match &bounty.state {
+        BountyState::Submitted(submission) => submission.claimant.clone(),
+        _ => panic!("The bounty doesn't have submitted work"),
+    };
+
+    bounty.state = BountyState::Released(claimant.clone());
+    set_bounty(&env, id, &bounty);
+
+    transfer_from_contract(&env, &get_payout_identifier(&env, &claimant), &BigInt::from_u64(&env, bounty.amount));
+
+    env.events().publish((symbol!("bounty"), symbol!("released")), (id, claimant));
+}
+
+fn cancel_bounty(env: &Env, id: u32) {
+    check_not_paused(&env);
+
+    let mut bounty = get_bounty(&env, id);
+
+    match bounty.state {
+        BountyState::Open | BountyState::Claimed(_) | BountyState::Submitted(_) => {},
+        _ => panic!("The bounty was already closed"),
+    }
+
+    bounty.state = BountyState::Cancelled;
+    set_bounty(&env, id, &bounty);
+
+    transfer_from_contract(&env, &get_admin_id(&env), &BigInt::from_u64(&env, bounty.amount));
+
+    env.events().publish((symbol!("bounty"), symbol!("cancelled")), id);
+}
+
+// Submitted work can still be reviewed after the deadline, so only bounties without it expire.
+fn expire_bounty(env: &Env, id: u32) {
+    check_not_paused(&env);
+
+    let mut bounty = get_bounty(&env, id);
+
+    match bounty.state {
+        BountyState::Open | BountyState::Claimed(_) => {},
+        _ => panic!("Only open or claimed bounties can expire"),
+    }
+
+    if env.ledger().timestamp() < bounty.deadline {
+        panic!("The bounty deadline isn't over yet");
+    }
+
+    bounty.state = BountyState::Expired;
+    set_bounty(&env, id, &bounty);
+
+    transfer_from_contract(&env, &get_admin_id(&env), &BigInt::from_u64(&env, bounty.amount));
+
+    env.events().publish((symbol!("bounty"), symbol!("expired")), id);
+}
+
+// ADMIN
+fn get_admin_id(env: &Env) -> Identifier {
+    let key = DataKey::AdminId;
+    env.data().get(key).unwrap().unwrap()
+}
+
+fn set_admin_id(env: &Env, account_id: &Identifier) {
+    env.data().set(DataKey::AdminId, account_id);
+}
+
+// TOKEN CONTRACT
+fn get_token_contract_id(env: &Env) -> BytesN<32> {
+    let key = DataKey::TokenId;
+    env.data().get(key).unwrap().unwrap()
+}
+
+fn set_token_id(e: &Env, token_id: &BytesN<32>) {
+    e.data().set(DataKey::TokenId, token_id);
+}
+
+// IDENTIFIER WRAPPERS
+pub fn get_account_identifier(account_id: AccountId) -> Identifier {
+    Identifier::Account(account_id)
+}
+
+pub fn get_contract_identifier(contract_id: BytesN<32>) -> Identifier {
+    Identifier::Contract(contract_id)
+}
+
+pub struct OrganizationContract;
+
+pub trait OrganizationContractTrait {
+    fn initialize(
+        e: Env,
         admin: Identifier,
         org_name: Symbol,
         rewards: Map<Symbol, u32>,
@@ -200,21 +2070,177 @@ pub trait OrganizationContractTrait {
         token_c_id:BytesN<32>
     );
 
-    fn add_m(env: Env, account: AccountId);
+    fn add_m(env: Env, admin_sign: Signature, nonce: BigInt, account: AccountId);
+
+    fn revoke_m(env: Env, admin_sign: Signature, nonce: BigInt, from: AccountId);
+
+    fn reward_m(e: Env, token_approval_sig: Signature, to: AccountId, r_type: Symbol) -> u32;
+
+    fn get_tc_id(env: Env) -> BytesN<32>;
+
+    fn get_bal(env: Env) -> BigInt;
+
+    fn org_name(env: Env) -> Symbol;
+
+    fn fund_c(env: Env, approval_sign: Signature);
+
+    fn get_m(env: Env, start: u32, limit: u32) -> Vec<Member>;
+
+    fn get_member(env: Env, account: AccountId) -> Member;
+
+    fn set_name(env: Env, name: Symbol);
+
+    fn set_payout(env: Env, admin_sign: Signature, nonce: BigInt, account: AccountId, payout: AccountId);
+
+    fn set_role(env: Env, admin_sign: Signature, nonce: BigInt, account: AccountId, role: Symbol);
+
+    fn set_status(env: Env, admin_sign: Signature, nonce: BigInt, account: AccountId, status: MemberStatus);
+
+    fn suspend(env: Env, admin_sign: Signature, nonce: BigInt, account: AccountId, until: u64);
+
+    fn reinstate(env: Env, admin_sign: Signature, nonce: BigInt, account: AccountId);
+
+    fn apply(env: Env, metadata: BytesN<32>);
+
+    fn get_apps(env: Env) -> Vec<Application>;
+
+    fn accept(env: Env, admin_sign: Signature, nonce: BigInt, account: AccountId);
+
+    fn reject(env: Env, admin_sign: Signature, nonce: BigInt, account: AccountId);
+
+    fn s_invite(env: Env, admin_sign: Signature, nonce: BigInt, code_hash: BytesN<32>, expires: u64, max_uses: u32);
+
+    fn redeem(env: Env, code: Bytes);
+
+    fn set_tiers(env: Env, admin_sign: Signature, nonce: BigInt, tiers: Vec<Tier>);
+
+    fn get_tiers(env: Env) -> Vec<Tier>;
+
+    fn set_tier(env: Env, admin_sign: Signature, nonce: BigInt, account: AccountId, tier: u32);
+
+    fn get_tier(env: Env, account: AccountId) -> u32;
+
+    fn earned(env: Env, account: AccountId) -> u64;
 
-    fn revoke_m(env: Env, from: AccountId);
+    fn reward_amt(env: Env, account: AccountId, r_type: Symbol) -> u32;
 
-    fn reward_m(e: Env, token_approval_sig: Signature, to: AccountId, r_type: Symbol);
+    fn s_decay(env: Env, admin_sign: Signature, nonce: BigInt, rate: u32, period: u64);
 
-    fn get_tc_id(env: Env) -> BytesN<32>;
+    fn rep(env: Env, account: AccountId) -> u64;
 
-    fn get_bal(env: Env) -> BigInt;
-    
-    fn get_m(env: Env) -> Vec<AccountId>;
+    fn rep_rank(env: Env, limit: u32) -> Vec<RepEntry>;
 
-    fn org_name(env: Env) -> Symbol;
-    
-    fn fund_c(env: Env, approval_sign: Signature);
+    fn s_badge(env: Env, admin_sign: Signature, nonce: BigInt, badge: Symbol, rule: BadgeRule);
+
+    fn g_badges(env: Env) -> Map<Symbol, BadgeRule>;
+
+    fn award(env: Env, admin_sign: Signature, nonce: BigInt, account: AccountId, badge: Symbol);
+
+    fn check_b(env: Env, account: AccountId);
+
+    fn badges(env: Env, account: AccountId) -> Vec<Symbol>;
+
+    fn holders(env: Env, badge: Symbol) -> Vec<AccountId>;
+
+    fn pause(env: Env, admin_sign: Signature, nonce: BigInt);
+
+    fn unpause(env: Env, admin_sign: Signature, nonce: BigInt);
+
+    fn paused(env: Env) -> bool;
+
+    fn version(env: Env) -> u32;
+
+    fn nonce(env: Env, id: Identifier) -> BigInt;
+
+    fn s_kudos(env: Env, admin_sign: Signature, nonce: BigInt, budget: u32, period: u64);
+
+    fn kudos(env: Env, to: AccountId, amount: u32, reason: Symbol);
+
+    fn kudos_left(env: Env, account: AccountId) -> u32;
+
+    fn kudos_recv(env: Env, account: AccountId) -> u32;
+
+    fn add_team(env: Env, admin_sign: Signature, nonce: BigInt, team: Symbol, manager: AccountId, budget: u32, period: u64);
+
+    fn team_add(env: Env, admin_sign: Signature, nonce: BigInt, team: Symbol, account: AccountId);
+
+    fn team_rm(env: Env, admin_sign: Signature, nonce: BigInt, team: Symbol, account: AccountId);
+
+    fn team_rwd(env: Env, team: Symbol, to: AccountId, r_type: Symbol) -> u32;
+
+    fn get_teams(env: Env) -> Vec<Symbol>;
+
+    fn get_team(env: Env, team: Symbol) -> Team;
+
+    fn team_spent(env: Env, team: Symbol) -> TeamSpend;
+
+    fn team_left(env: Env, team: Symbol) -> u32;
+
+    fn open_s(env: Env, admin_sign: Signature, nonce: BigInt, member: AccountId, rate: u32, start: u64, end: u64) -> u32;
+
+    fn withdraw_s(env: Env, id: u32);
+
+    fn cancel_s(env: Env, admin_sign: Signature, nonce: BigInt, id: u32);
+
+    fn get_s(env: Env, id: u32) -> Stream;
+
+    fn streams(env: Env, member: AccountId) -> Vec<u32>;
+
+    fn s_balance(env: Env, id: u32) -> u64;
+
+    fn open_ep(env: Env, admin_sign: Signature, nonce: BigInt, pool: u64, end: u64, claim_end: u64) -> u32;
+
+    fn accrue(env: Env, admin_sign: Signature, nonce: BigInt, to: AccountId, r_type: Symbol);
+
+    fn finalize(env: Env, epoch: u32);
+
+    fn claim_ep(env: Env, epoch: u32);
+
+    fn sweep_ep(env: Env, admin_sign: Signature, nonce: BigInt, epoch: u32);
+
+    fn get_ep(env: Env, epoch: u32) -> Epoch;
+
+    fn ep_points(env: Env, epoch: u32, account: AccountId) -> u64;
+
+    fn ep_share(env: Env, epoch: u32, account: AccountId) -> u64;
+
+    fn s_gov(env: Env, admin_sign: Signature, nonce: BigInt, config: GovConfig);
+
+    fn get_gov(env: Env) -> GovConfig;
+
+    fn propose(env: Env, action: Action) -> u32;
+
+    fn vote(env: Env, proposal: u32, support: bool);
+
+    fn execute(env: Env, proposal: u32);
+
+    fn get_prop(env: Env, proposal: u32) -> Proposal;
+
+    fn get_rwds(env: Env) -> Map<Symbol, u32>;
+
+    fn post_b(env: Env, admin_sign: Signature, nonce: BigInt, desc: BytesN<32>, amount: u64, deadline: u64, reviewer: AccountId) -> u32;
+
+    fn claim_b(env: Env, bounty: u32);
+
+    fn submit_b(env: Env, bounty: u32, work: BytesN<32>);
+
+    fn release_b(env: Env, bounty: u32);
+
+    fn cancel_b(env: Env, admin_sign: Signature, nonce: BigInt, bounty: u32);
+
+    fn expire_b(env: Env, bounty: u32);
+
+    fn get_b(env: Env, bounty: u32) -> Bounty;
+
+    fn s_dispute(env: Env, admin_sign: Signature, nonce: BigInt, window: u64);
+
+    fn dispute(env: Env) -> u64;
+
+    fn reverse(env: Env, admin_sign: Signature, nonce: BigInt, reward: u32);
+
+    fn get_rwd(env: Env, reward: u32) -> Reward;
+
+    fn m_rwds(env: Env, account: AccountId) -> Vec<u32>;
 }
 
 #[contractimpl]
@@ -227,6 +2253,10 @@ impl OrganizationContractTrait for OrganizationContract {
         fund_amount: u32,
         token_c_id: BytesN<32>
     ) {
+        if env.data().has(DataKey::AdminId) {
+            panic!("The organization is already initialized");
+        }
+
         set_admin_id(&env, &admin);
         
         set_organization_name(&env, org_name);
@@ -236,18 +2266,26 @@ impl OrganizationContractTrait for OrganizationContract {
         set_token_id(&env, &token_c_id);
 
         set_rewards(&env, &rewards);
+
+        set_schema_version(&env, SCHEMA_VERSION);
     }
 
-    fn add_m(env: Env, account: AccountId) {
+    fn add_m(env: Env, admin_sign: Signature, nonce: BigInt, account: AccountId) {
+        check_admin(&env, &admin_sign, &nonce);
+        verify(&env, &admin_sign, symbol!("add_m"), (admin_sign.identifier(&env), nonce, account.clone()));
+
         add_member(&env, account);
     }
     
-    fn revoke_m(env: Env, from: AccountId) {
+    fn revoke_m(env: Env, admin_sign: Signature, nonce: BigInt, from: AccountId) {
+        check_admin(&env, &admin_sign, &nonce);
+        verify(&env, &admin_sign, symbol!("revoke_m"), (admin_sign.identifier(&env), nonce, from.clone()));
+
         revoke_membership(&env, &from);
     }
 
-    fn reward_m(env: Env, approval_sign: Signature, to: AccountId, r_type: Symbol) {
-        reward_member(&env, &approval_sign, &to, &r_type);
+    fn reward_m(env: Env, approval_sign: Signature, to: AccountId, r_type: Symbol) -> u32 {
+        reward_member(&env, &approval_sign, &to, &r_type)
     }
     
     fn get_tc_id(env: Env) -> BytesN<32> {
@@ -266,8 +2304,445 @@ impl OrganizationContractTrait for OrganizationContract {
         fund_contract_balance(&env, &approval_sign);
     }
 
-    fn get_m(env: Env) -> Vec<AccountId> {
-        get_members(&env)
+    fn get_m(env: Env, start: u32, limit: u32) -> Vec<Member> {
+        get_members_page(&env, start, limit)
+    }
+
+    fn get_member(env: Env, account: AccountId) -> Member {
+        get_member(&env, &account)
+    }
+
+    fn set_name(env: Env, name: Symbol) {
+        let mut member = get_invoker_member(&env);
+        member.name = name;
+        set_member(&env, &member);
+    }
+
+    fn set_payout(env: Env, admin_sign: Signature, nonce: BigInt, account: AccountId, payout: AccountId) {
+        check_admin(&env, &admin_sign, &nonce);
+        verify(&env, &admin_sign, symbol!("set_payout"), (admin_sign.identifier(&env), nonce, account.clone(), payout.clone()));
+
+        let mut member = get_member(&env, &account);
+        member.payout = payout;
+        set_member(&env, &member);
+    }
+
+    fn set_role(env: Env, admin_sign: Signature, nonce: BigInt, account: AccountId, role: Symbol) {
+        check_admin(&env, &admin_sign, &nonce);
+        verify(&env, &admin_sign, symbol!("set_role"), (admin_sign.identifier(&env), nonce, account.clone(), role.clone()));
+
+        let mut member = get_member(&env, &account);
+        member.role = role;
+        set_member(&env, &member);
+    }
+
+    fn set_status(env: Env, admin_sign: Signature, nonce: BigInt, account: AccountId, status: MemberStatus) {
+        check_admin(&env, &admin_sign, &nonce);
+        verify(&env, &admin_sign, symbol!("set_status"), (admin_sign.identifier(&env), nonce, account.clone(), status.clone()));
+
+        let mut member = get_member(&env, &account);
+        member.status = status;
+        set_member(&env, &member);
+
+        env.data().remove(DataKey::SuspUntil(account));
+    }
+
+    fn suspend(env: Env, admin_sign: Signature, nonce: BigInt, account: AccountId, until: u64) {
+        check_admin(&env, &admin_sign, &nonce);
+        verify(&env, &admin_sign, symbol!("suspend"), (admin_sign.identifier(&env), nonce, account.clone(), until));
+
+        suspend_member(&env, &account, until);
+    }
+
+    fn reinstate(env: Env, admin_sign: Signature, nonce: BigInt, account: AccountId) {
+        check_admin(&env, &admin_sign, &nonce);
+        verify(&env, &admin_sign, symbol!("reinstate"), (admin_sign.identifier(&env), nonce, account.clone()));
+
+        reinstate_member(&env, &account);
+    }
+
+    fn apply(env: Env, metadata: BytesN<32>) {
+        let account = get_invoker_account(&env);
+        apply_for_membership(&env, &account, &metadata);
+    }
+
+    fn get_apps(env: Env) -> Vec<Application> {
+        get_applications(&env)
+    }
+
+    fn accept(env: Env, admin_sign: Signature, nonce: BigInt, account: AccountId) {
+        check_admin(&env, &admin_sign, &nonce);
+        verify(&env, &admin_sign, symbol!("accept"), (admin_sign.identifier(&env), nonce, account.clone()));
+
+        close_application(&env, &account);
+        add_member(&env, account);
+    }
+
+    fn reject(env: Env, admin_sign: Signature, nonce: BigInt, account: AccountId) {
+        check_admin(&env, &admin_sign, &nonce);
+        verify(&env, &admin_sign, symbol!("reject"), (admin_sign.identifier(&env), nonce, account.clone()));
+
+        close_application(&env, &account);
+    }
+
+    fn s_invite(env: Env, admin_sign: Signature, nonce: BigInt, code_hash: BytesN<32>, expires: u64, max_uses: u32) {
+        check_admin(&env, &admin_sign, &nonce);
+        verify(&env, &admin_sign, symbol!("s_invite"), (admin_sign.identifier(&env), nonce, code_hash.clone(), expires, max_uses));
+
+        set_invite(&env, &code_hash, &Invite { expires, uses_left: max_uses });
+    }
+
+    fn redeem(env: Env, code: Bytes) {
+        let account = get_invoker_account(&env);
+        redeem_invite(&env, &account, &code);
+    }
+
+    fn set_tiers(env: Env, admin_sign: Signature, nonce: BigInt, tiers: Vec<Tier>) {
+        check_admin(&env, &admin_sign, &nonce);
+        verify(&env, &admin_sign, symbol!("set_tiers"), (admin_sign.identifier(&env), nonce, tiers.clone()));
+
+        set_tiers(&env, &tiers);
+    }
+
+    fn get_tiers(env: Env) -> Vec<Tier> {
+        get_tiers(&env)
+    }
+
+    fn set_tier(env: Env, admin_sign: Signature, nonce: BigInt, account: AccountId, tier: u32) {
+        check_admin(&env, &admin_sign, &nonce);
+        verify(&env, &admin_sign, symbol!("set_tier"), (admin_sign.identifier(&env), nonce, account.clone(), tier));
+
+        if !is_member(&env, &account) {
+            panic!("The account doesn't belong to the organization");
+        }
+
+        set_member_tier(&env, &account, tier);
+    }
+
+    fn get_tier(env: Env, account: AccountId) -> u32 {
+        get_member_tier(&env, &account)
+    }
+
+    fn earned(env: Env, account: AccountId) -> u64 {
+        get_earned(&env, &account)
+    }
+
+    fn reward_amt(env: Env, account: AccountId, r_type: Symbol) -> u32 {
+        get_member_reward_value(&env, &account, &r_type)
+    }
+
+    fn s_decay(env: Env, admin_sign: Signature, nonce: BigInt, rate: u32, period: u64) {
+        check_admin(&env, &admin_sign, &nonce);
+        verify(&env, &admin_sign, symbol!("s_decay"), (admin_sign.identifier(&env), nonce, rate, period));
+
+        set_reputation_decay(&env, &RepDecay { rate, period });
+    }
+
+    fn rep(env: Env, account: AccountId) -> u64 {
+        get_reputation(&env, &account)
+    }
+
+    fn rep_rank(env: Env, limit: u32) -> Vec<RepEntry> {
+        get_reputation_ranking(&env, limit)
+    }
+
+    fn s_badge(env: Env, admin_sign: Signature, nonce: BigInt, badge: Symbol, rule: BadgeRule) {
+        check_admin(&env, &admin_sign, &nonce);
+        verify(&env, &admin_sign, symbol!("s_badge"), (admin_sign.identifier(&env), nonce, badge.clone(), rule.clone()));
+
+        set_badge(&env, &badge, &rule);
+    }
+
+    fn g_badges(env: Env) -> Map<Symbol, BadgeRule> {
+        get_badge_catalog(&env)
+    }
+
+    fn award(env: Env, admin_sign: Signature, nonce: BigInt, account: AccountId, badge: Symbol) {
+        check_admin(&env, &admin_sign, &nonce);
+        verify(&env, &admin_sign, symbol!("award"), (admin_sign.identifier(&env), nonce, account.clone(), badge.clone()));
+
+        if !is_member(&env, &account) {
+            panic!("The account doesn't belong to the organization");
+        }
+
+        award_badge(&env, &account, &badge);
+    }
+
+    fn check_b(env: Env, account: AccountId) {
+        if !is_member(&env, &account) {
+            panic!("The account doesn't belong to the organization");
+        }
+
+        check_badges(&env, &account);
+    }
+
+    fn badges(env: Env, account: AccountId) -> Vec<Symbol> {
+        get_member_badges(&env, &account)
+    }
+
+    fn holders(env: Env, badge: Symbol) -> Vec<AccountId> {
+        get_badge_holders(&env, &badge)
+    }
+
+    fn pause(env: Env, admin_sign: Signature, nonce: BigInt) {
+        check_admin(&env, &admin_sign, &nonce);
+        verify(&env, &admin_sign, symbol!("pause"), (admin_sign.identifier(&env), nonce));
+
+        set_paused(&env, true);
+    }
+
+    fn unpause(env: Env, admin_sign: Signature, nonce: BigInt) {
+        check_admin(&env, &admin_sign, &nonce);
+        verify(&env, &admin_sign, symbol!("unpause"), (admin_sign.identifier(&env), nonce));
+
+        set_paused(&env, false);
+    }
+
+    fn paused(env: Env) -> bool {
+        is_paused(&env)
+    }
+
+    fn version(env: Env) -> u32 {
+        get_schema_version(&env)
+    }
+
+    fn nonce(env: Env, id: Identifier) -> BigInt {
+        read_nonce(&env, &id)
+    }
+
+    fn s_kudos(env: Env, admin_sign: Signature, nonce: BigInt, budget: u32, period: u64) {
+        check_admin(&env, &admin_sign, &nonce);
+        verify(&env, &admin_sign, symbol!("s_kudos"), (admin_sign.identifier(&env), nonce, budget, period));
+
+        set_kudos_config(&env, &KudosConfig { budget, period });
+    }
+
+    fn kudos(env: Env, to: AccountId, amount: u32, reason: Symbol) {
+        let from = get_invoker_account(&env);
+        send_kudos(&env, &from, &to, amount, &reason);
+    }
+
+    fn kudos_left(env: Env, account: AccountId) -> u32 {
+        get_kudos_left(&env, &account)
+    }
+
+    fn kudos_recv(env: Env, account: AccountId) -> u32 {
+        get_kudos_received(&env, &account)
+    }
+
+    fn add_team(env: Env, admin_sign: Signature, nonce: BigInt, team: Symbol, manager: AccountId, budget: u32, period: u64) {
+        check_admin(&env, &admin_sign, &nonce);
+        verify(&env, &admin_sign, symbol!("add_team"), (admin_sign.identifier(&env), nonce, team.clone(), manager.clone(), budget, period));
+
+        if env.data().has(DataKey::Team(team.clone())) {
+            panic!("The team already exists");
+        }
+
+        set_team(&env, &team, &Team { manager, budget, period, members: vec![&env] });
+    }
+
+    fn team_add(env: Env, admin_sign: Signature, nonce: BigInt, team: Symbol, account: AccountId) {
+        check_admin(&env, &admin_sign, &nonce);
+        verify(&env, &admin_sign, symbol!("team_add"), (admin_sign.identifier(&env), nonce, team.clone(), account.clone()));
+
+        add_team_member(&env, &team, &account);
+    }
+
+    fn team_rm(env: Env, admin_sign: Signature, nonce: BigInt, team: Symbol, account: AccountId) {
+        check_admin(&env, &admin_sign, &nonce);
+        verify(&env, &admin_sign, symbol!("team_rm"), (admin_sign.identifier(&env), nonce, team.clone(), account.clone()));
+
+        remove_team_member(&env, &team, &account);
+    }
+
+    fn team_rwd(env: Env, team: Symbol, to: AccountId, r_type: Symbol) -> u32 {
+        let manager = get_invoker_account(&env);
+        reward_team_member(&env, &manager, &team, &to, &r_type)
+    }
+
+    fn get_teams(env: Env) -> Vec<Symbol> {
+        get_teams(&env)
+    }
+
+    fn get_team(env: Env, team: Symbol) -> Team {
+        get_team(&env, &team)
+    }
+
+    fn team_spent(env: Env, team: Symbol) -> TeamSpend {
+        get_team_spend(&env, &team)
+    }
+
+    fn team_left(env: Env, team: Symbol) -> u32 {
+        get_team_budget_left(&env, &team)
+    }
+
+    fn open_s(env: Env, admin_sign: Signature, nonce: BigInt, member: AccountId, rate: u32, start: u64, end: u64) -> u32 {
+        check_admin(&env, &admin_sign, &nonce);
+        verify(&env, &admin_sign, symbol!("open_s"), (admin_sign.identifier(&env), nonce, member.clone(), rate, start, end));
+
+        open_stream(&env, &member, rate, start, end)
+    }
+
+    fn withdraw_s(env: Env, id: u32) {
+        let member = get_invoker_account(&env);
+        withdraw_stream(&env, &member, id);
+    }
+
+    fn cancel_s(env: Env, admin_sign: Signature, nonce: BigInt, id: u32) {
+        check_admin(&env, &admin_sign, &nonce);
+        verify(&env, &admin_sign, symbol!("cancel_s"), (admin_sign.identifier(&env), nonce, id));
+
+        cancel_stream(&env, id);
+    }
+
+    fn get_s(env: Env, id: u32) -> Stream {
+        get_stream(&env, id)
+    }
+
+    fn streams(env: Env, member: AccountId) -> Vec<u32> {
+        get_member_streams(&env, &member)
+    }
+
+    fn s_balance(env: Env, id: u32) -> u64 {
+        get_withdrawable_amount(&env, &get_stream(&env, id))
+    }
+
+    fn open_ep(env: Env, admin_sign: Signature, nonce: BigInt, pool: u64, end: u64, claim_end: u64) -> u32 {
+        check_admin(&env, &admin_sign, &nonce);
+        verify(&env, &admin_sign, symbol!("open_ep"), (admin_sign.identifier(&env), nonce, pool, end, claim_end));
+
+        open_epoch(&env, pool, end, claim_end)
+    }
+
+    fn accrue(env: Env, admin_sign: Signature, nonce: BigInt, to: AccountId, r_type: Symbol) {
+        check_admin(&env, &admin_sign, &nonce);
+        verify(&env, &admin_sign, symbol!("accrue"), (admin_sign.identifier(&env), nonce, to.clone(), r_type.clone()));
+
+        accrue_points(&env, &to, &r_type);
+    }
+
+    fn finalize(env: Env, epoch: u32) {
+        finalize_epoch(&env, epoch);
+    }
+
+    fn claim_ep(env: Env, epoch: u32) {
+        let account = get_invoker_account(&env);
+        claim_epoch_share(&env, &account, epoch);
+    }
+
+    fn sweep_ep(env: Env, admin_sign: Signature, nonce: BigInt, epoch: u32) {
+        check_admin(&env, &admin_sign, &nonce);
+        verify(&env, &admin_sign, symbol!("sweep_ep"), (admin_sign.identifier(&env), nonce, epoch));
+
+        sweep_epoch(&env, epoch);
+    }
+
+    fn get_ep(env: Env, epoch: u32) -> Epoch {
+        get_epoch(&env, epoch)
+    }
+
+    fn ep_points(env: Env, epoch: u32, account: AccountId) -> u64 {
+        get_epoch_points(&env, epoch, &account)
+    }
+
+    fn ep_share(env: Env, epoch: u32, account: AccountId) -> u64 {
+        get_epoch_share(&env, epoch, &account)
+    }
+
+    fn s_gov(env: Env, admin_sign: Signature, nonce: BigInt, config: GovConfig) {
+        check_admin(&env, &admin_sign, &nonce);
+        verify(&env, &admin_sign, symbol!("s_gov"), (admin_sign.identifier(&env), nonce, config.clone()));
+
+        set_gov_config(&env, &config);
+    }
+
+    fn get_gov(env: Env) -> GovConfig {
+        get_gov_config(&env)
+    }
+
+    fn propose(env: Env, action: Action) -> u32 {
+        let proposer = get_invoker_account(&env);
+        create_proposal(&env, &proposer, &action)
+    }
+
+    fn vote(env: Env, proposal: u32, support: bool) {
+        let voter = get_invoker_account(&env);
+        cast_vote(&env, &voter, proposal, support);
+    }
+
+    fn execute(env: Env, proposal: u32) {
+        execute_proposal(&env, proposal);
+    }
+
+    fn get_prop(env: Env, proposal: u32) -> Proposal {
+        get_proposal(&env, proposal)
+    }
+
+    fn get_rwds(env: Env) -> Map<Symbol, u32> {
+        get_rewards(&env)
+    }
+
+    fn post_b(env: Env, admin_sign: Signature, nonce: BigInt, desc: BytesN<32>, amount: u64, deadline: u64, reviewer: AccountId) -> u32 {
+        check_admin(&env, &admin_sign, &nonce);
+        verify(&env, &admin_sign, symbol!("post_b"), (admin_sign.identifier(&env), nonce, desc.clone(), amount, deadline, reviewer.clone()));
+
+        post_bounty(&env, &desc, amount, deadline, &reviewer)
+    }
+
+    fn claim_b(env: Env, bounty: u32) {
+        let account = get_invoker_account(&env);
+        claim_bounty(&env, &account, bounty);
+    }
+
+    fn submit_b(env: Env, bounty: u32, work: BytesN<32>) {
+        let account = get_invoker_account(&env);
+        submit_bounty(&env, &account, bounty, &work);
+    }
+
+    fn release_b(env: Env, bounty: u32) {
+        let reviewer = get_invoker_account(&env);
+        release_bounty(&env, &reviewer, bounty);
+    }
+
+    fn cancel_b(env: Env, admin_sign: Signature, nonce: BigInt, bounty: u32) {
+        check_admin(&env, &admin_sign, &nonce);
+        verify(&env, &admin_sign, symbol!("cancel_b"), (admin_sign.identifier(&env), nonce, bounty));
+
+        cancel_bounty(&env, bounty);
+    }
+
+    fn expire_b(env: Env, bounty: u32) {
+        expire_bounty(&env, bounty);
+    }
+
+    fn get_b(env: Env, bounty: u32) -> Bounty {
+        get_bounty(&env, bounty)
+    }
+
+    fn s_dispute(env: Env, admin_sign: Signature, nonce: BigInt, window: u64) {
+        check_admin(&env, &admin_sign, &nonce);
+        verify(&env, &admin_sign, symbol!("s_dispute"), (admin_sign.identifier(&env), nonce, window));
+
+        set_dispute_window(&env, window);
+    }
+
+    fn dispute(env: Env) -> u64 {
+        get_dispute_window(&env)
+    }
+
+    fn reverse(env: Env, admin_sign: Signature, nonce: BigInt, reward: u32) {
+        check_admin(&env, &admin_sign, &nonce);
+        verify(&env, &admin_sign, symbol!("reverse"), (admin_sign.identifier(&env), nonce, reward));
+
+        reverse_reward(&env, reward);
+    }
+
+    fn get_rwd(env: Env, reward: u32) -> Reward {
+        get_reward_record(&env, reward)
+    }
+
+    fn m_rwds(env: Env, account: AccountId) -> Vec<u32> {
+        get_member_rewards(&env, &account)
     }
 }
 
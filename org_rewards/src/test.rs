@@ -1,9 +1,9 @@
 #![cfg(test)]
 
-use super::{OrganizationContract, OrganizationContractClient, Identifier};
+use super::{OrganizationContract, OrganizationContractClient, Identifier, MemberStatus, Tier, BadgeRule, RewardCount, Action, GovConfig, RewardValue, VoteWeight, BountyState};
 
-use soroban_sdk::{symbol, Env, testutils::{Accounts}, BigInt, IntoVal, BytesN, Map, Symbol};
-use soroban_auth::{Signature, testutils::ed25519};
+use soroban_sdk::{symbol, vec, AccountId, Env, testutils::{Accounts, Ledger, LedgerInfo, ed25519::Sign}, BigInt, IntoVal, Bytes, BytesN, Map, Symbol};
+use soroban_auth::{Signature, SignaturePayload, testutils::ed25519};
 
 extern crate std;
 
@@ -25,6 +25,49 @@ fn create_and_init_token_contract(env: &Env, admin_id: &Identifier) -> (BytesN<3
     (token_id, token_client)
 }
 
+fn add_member(
+    env: &Env,
+    contract_client: &OrganizationContractClient,
+    contract_id: &BytesN<32>,
+    admin_id: &Identifier,
+    admin_sign: &impl Sign<SignaturePayload, Signature = [u8; 64]>,
+    account: &AccountId,
+) {
+    let nonce = contract_client.nonce(admin_id);
+    let add_sign = ed25519::sign(
+        env,
+        admin_sign,
+        contract_id,
+        symbol!("add_m"),
+        (admin_id, &nonce, account),
+    );
+    contract_client.add_m(&add_sign, &nonce, account);
+}
+
+// The admin is an account here, so the paused tests can call the admin entrypoints as the invoker.
+fn create_paused_organization(env: &Env) -> (OrganizationContractClient, AccountId, AccountId, AccountId) {
+    let admin = env.accounts().generate();
+    let admin_id = Identifier::Account(admin.clone());
+
+    let member = env.accounts().generate();
+    let doe_user = env.accounts().generate();
+
+    let contract_id = env.register_contract(None, OrganizationContract);
+    let contract_client = OrganizationContractClient::new(&env, &contract_id);
+
+    let (token_id, _token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    let items = [(symbol!("talk"), 35), (symbol!("blog_post"), 25)];
+    let rewards: Map<Symbol, u32> = Map::from_array(&env, items);
+
+    contract_client.initialize(&admin_id, &symbol!("Kommit"), &rewards, &1000, &token_id);
+    contract_client.with_source_account(&admin).add_m(&Signature::Invoker, &BigInt::zero(&env), &member);
+    contract_client.with_source_account(&admin).add_m(&Signature::Invoker, &BigInt::zero(&env), &doe_user);
+    contract_client.with_source_account(&admin).pause(&Signature::Invoker, &BigInt::zero(&env));
+
+    (contract_client, admin, member, doe_user)
+}
+
 #[test]
 fn happy_path() {
     let env = Env::default();
@@ -105,12 +148,13 @@ fn happy_path() {
         (&admin_id, &nonce, &member_id, &BigInt::from_u32(&env, reward_amount)),
     );
 
-    contract_client.add_m(&member);
+    add_member(&env, &contract_client, &contract_id, &admin_id, &admin_sign, &member);
 
     //Validate member was correctly inserted
-    assert!(
-        contract_client.get_m().contains(&member),
-        "Member was successfully removed"
+    assert_eq!(
+        contract_client.get_m(&0, &10).get(0).unwrap().unwrap().account,
+        member,
+        "Member was successfully inserted"
     );
 
     contract_client.reward_m(&xfer_approval_sign, &member, &symbol!("talk"));
@@ -126,24 +170,35 @@ fn happy_path() {
     std::println!("======= APPROBAL USER BALANCE - AFTER REWARD ========: {}", token_client.balance(&member_id));
     std::println!("=======================================================\n\n");
 
-    contract_client.add_m(&doe_user);
+    add_member(&env, &contract_client, &contract_id, &admin_id, &admin_sign, &doe_user);
 
-    std::println!("======= CONTRACT MEMBERS ========: {:?}", contract_client.get_m());
+    std::println!("======= CONTRACT MEMBERS ========: {:?}", contract_client.get_m(&0, &10));
 
     token_client.with_source_account(&member).approve(
         &Signature::Invoker,
         &BigInt::zero(&env),
-        &Identifier::Contract(contract_id),
+        &Identifier::Contract(contract_id.clone()),
         &token_client.balance(&member_id)
     );
 
     std::println!("======= APPROBAL USER BALANCE - AFTER APPROVE ========: {}", token_client.balance(&member_id));
 
-    contract_client.revoke_m(&member);
+    let nonce = contract_client.nonce(&admin_id);
+    let revoke_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("revoke_m"),
+        (&admin_id, &nonce, &member),
+    );
+    contract_client.revoke_m(&revoke_sign, &nonce, &member);
 
     // Member was correctly removed from organization
-    assert!(
-        !contract_client.get_m().contains(member),
+    let members = contract_client.get_m(&0, &10);
+    assert_eq!(members.len(), 1, "Member was successfully removed");
+    assert_eq!(
+        members.get(0).unwrap().unwrap().account,
+        doe_user,
         "Member was successfully removed"
     );
 
@@ -163,7 +218,7 @@ fn happy_path() {
 
     std::println!("======= ADMIN BALANCE - AFTER REMOVE ========: {}", token_client.balance(&admin_id));
     std::println!("======= APPROBAL USER BALANCE - AFTER REMOVE ========: {}", token_client.balance(&member_id));
-    std::println!("======= CONTRACT MEMBERS ========: {:?}", contract_client.get_m());
+    std::println!("======= CONTRACT MEMBERS ========: {:?}", contract_client.get_m(&0, &10));
 }
 
 #[test]
@@ -257,11 +312,20 @@ fn reward_no_member_account() {
     token_client.with_source_account(&doe_user).approve(
         &Signature::Invoker,
         &BigInt::zero(&env),
-        &Identifier::Contract(contract_id),
+        &Identifier::Contract(contract_id.clone()),
         &token_client.balance(&doe_user_id)
     );
 
-    contract_client.revoke_m(&doe_user);
+
+    let nonce = contract_client.nonce(&admin_id);
+    let revoke_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("revoke_m"),
+        (&admin_id, &nonce, &doe_user),
+    );
+    contract_client.revoke_m(&revoke_sign, &nonce, &doe_user);
 }
 
 #[test]
@@ -292,6 +356,8 @@ fn reward_with_invalid_type() {
         &token_id
     );
 
+    add_member(&env, &contract_client, &contract_id, &admin_id, &admin_sign, &doe_user);
+
     let nonce = token_client.nonce(&admin_id);
     let approval_sign = ed25519::sign(
         &env,
@@ -302,7 +368,6 @@ fn reward_with_invalid_type() {
     );
 
     contract_client.fund_c(&approval_sign);
-    contract_client.add_m(&doe_user);
 
     let xfer_approval_sign = ed25519::sign(
         &env,
@@ -314,3 +379,2265 @@ fn reward_with_invalid_type() {
 
     contract_client.reward_m(&xfer_approval_sign, &doe_user, &symbol!("oss_contri"));
 }
+
+#[test]
+fn members_send_kudos_from_their_budget() {
+    let env = Env::default();
+
+    let (admin_id, admin_sign) = ed25519::generate(&env);
+
+    let member = env.accounts().generate();
+    let peer = env.accounts().generate();
+    let peer_id = Identifier::Account(peer.clone());
+
+    let contract_id = env.register_contract(None, OrganizationContract);
+    let contract_client = OrganizationContractClient::new(&env, &contract_id);
+
+    let (token_id, token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    let allowed_funds_to_issue = 1000;
+    let org_name = symbol!("Kommit");
+    let items = [(symbol!("talk"), 35), (symbol!("blog_post"), 25)];
+    let rewards: Map<Symbol, u32> = Map::from_array(&env, items);
+
+    contract_client.initialize(
+        &admin_id,
+        &org_name,
+        &rewards,
+        &allowed_funds_to_issue,
+        &token_id
+    );
+
+    let nonce = token_client.nonce(&admin_id);
+    let approval_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &token_id,
+        symbol!("mint"),
+        (&admin_id, &nonce, &admin_id, &BigInt::from_u32(&env, allowed_funds_to_issue)),
+    );
+    contract_client.fund_c(&approval_sign);
+
+    // The kudos are paid from the treasury through an allowance
+    let nonce = token_client.nonce(&admin_id);
+    let allowance = BigInt::from_u32(&env, 100);
+    let allowance_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &token_id,
+        symbol!("approve"),
+        (&admin_id, &nonce, &Identifier::Contract(contract_id.clone()), &allowance),
+    );
+    token_client.approve(&allowance_sign, &nonce, &Identifier::Contract(contract_id.clone()), &allowance);
+
+    let nonce = contract_client.nonce(&admin_id);
+    let kudos_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("s_kudos"),
+        (&admin_id, &nonce, &20u32, &604800u64),
+    );
+    contract_client.s_kudos(&kudos_sign, &nonce, &20, &604800);
+
+    add_member(&env, &contract_client, &contract_id, &admin_id, &admin_sign, &member);
+    add_member(&env, &contract_client, &contract_id, &admin_id, &admin_sign, &peer);
+
+    contract_client.with_source_account(&member).kudos(&peer, &15, &symbol!("review"));
+
+    assert_eq!(contract_client.kudos_left(&member), 5, "Kudos budget is consumed");
+    assert_eq!(contract_client.kudos_left(&peer), 20, "Receiving kudos doesn't consume the budget");
+    assert_eq!(contract_client.kudos_recv(&peer), 15, "Received kudos are tracked");
+    assert_eq!(
+        token_client.balance(&peer_id),
+        BigInt::from_u32(&env, 15),
+        "Kudos are paid from the treasury"
+    );
+}
+
+#[test]
+#[should_panic(expected = "Suspended members can't send kudos")]
+fn suspended_members_cannot_send_kudos() {
+    let env = Env::default();
+
+    let (admin_id, admin_sign) = ed25519::generate(&env);
+
+    let member = env.accounts().generate();
+    let peer = env.accounts().generate();
+
+    let contract_id = env.register_contract(None, OrganizationContract);
+    let contract_client = OrganizationContractClient::new(&env, &contract_id);
+
+    let (token_id, _token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    let items = [(symbol!("talk"), 35), (symbol!("blog_post"), 25)];
+    let rewards: Map<Symbol, u32> = Map::from_array(&env, items);
+
+    contract_client.initialize(&admin_id, &symbol!("Kommit"), &rewards, &1000, &token_id);
+
+    add_member(&env, &contract_client, &contract_id, &admin_id, &admin_sign, &member);
+    add_member(&env, &contract_client, &contract_id, &admin_id, &admin_sign, &peer);
+
+    let nonce = contract_client.nonce(&admin_id);
+    let suspend_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("suspend"),
+        (&admin_id, &nonce, &member, &0u64),
+    );
+    contract_client.suspend(&suspend_sign, &nonce, &member, &0);
+
+    contract_client.with_source_account(&member).kudos(&peer, &5, &symbol!("review"));
+}
+
+#[test]
+#[should_panic(expected = "Members can't send kudos to themselves")]
+fn self_kudos_are_forbidden() {
+    let env = Env::default();
+
+    let (admin_id, admin_sign) = ed25519::generate(&env);
+
+    let member = env.accounts().generate();
+
+    let contract_id = env.register_contract(None, OrganizationContract);
+    let contract_client = OrganizationContractClient::new(&env, &contract_id);
+
+    let (token_id, _token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    let items = [(symbol!("talk"), 35), (symbol!("blog_post"), 25)];
+    let rewards: Map<Symbol, u32> = Map::from_array(&env, items);
+
+    contract_client.initialize(&admin_id, &symbol!("Kommit"), &rewards, &1000, &token_id);
+
+    add_member(&env, &contract_client, &contract_id, &admin_id, &admin_sign, &member);
+
+    contract_client.with_source_account(&member).kudos(&member, &1, &symbol!("review"));
+}
+
+#[test]
+fn members_keep_profiles() {
+    let env = Env::default();
+
+    let (admin_id, admin_sign) = ed25519::generate(&env);
+
+    let member = env.accounts().generate();
+    let payout = env.accounts().generate();
+    let doe_user = env.accounts().generate();
+
+    let contract_id = env.register_contract(None, OrganizationContract);
+    let contract_client = OrganizationContractClient::new(&env, &contract_id);
+
+    let (token_id, _token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    let items = [(symbol!("talk"), 35), (symbol!("blog_post"), 25)];
+    let rewards: Map<Symbol, u32> = Map::from_array(&env, items);
+
+    contract_client.initialize(&admin_id, &symbol!("Kommit"), &rewards, &1000, &token_id);
+
+    add_member(&env, &contract_client, &contract_id, &admin_id, &admin_sign, &member);
+    add_member(&env, &contract_client, &contract_id, &admin_id, &admin_sign, &doe_user);
+
+    contract_client.with_source_account(&member).set_name(&symbol!("alice"));
+
+    let nonce = contract_client.nonce(&admin_id);
+    let payout_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("set_payout"),
+        (&admin_id, &nonce, &member, &payout),
+    );
+    contract_client.set_payout(&payout_sign, &nonce, &member, &payout);
+
+    let nonce = contract_client.nonce(&admin_id);
+    let role_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("set_role"),
+        (&admin_id, &nonce, &member, &symbol!("devops")),
+    );
+    contract_client.set_role(&role_sign, &nonce, &member, &symbol!("devops"));
+
+    let nonce = contract_client.nonce(&admin_id);
+    let status_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("set_status"),
+        (&admin_id, &nonce, &doe_user, &MemberStatus::Alumni),
+    );
+    contract_client.set_status(&status_sign, &nonce, &doe_user, &MemberStatus::Alumni);
+
+    let profile = contract_client.get_member(&member);
+    assert_eq!(profile.name, symbol!("alice"), "Members update their own name");
+    assert_eq!(profile.role, symbol!("devops"), "The admin sets the member's role");
+    assert_eq!(profile.payout, payout, "The admin sets the member's payout address");
+    assert_eq!(profile.status, MemberStatus::Active, "New members are active");
+
+    let page = contract_client.get_m(&1, &10);
+    assert_eq!(page.len(), 1, "Members are paginated");
+    assert_eq!(page.get(0).unwrap().unwrap().status, MemberStatus::Alumni, "The admin sets the member's status");
+}
+
+#[test]
+#[should_panic(expected = "The user account you're trying to reward is suspended")]
+fn reward_suspended_member() {
+    let env = Env::default();
+
+    let (admin_id, admin_sign) = ed25519::generate(&env);
+
+    let doe_user = env.accounts().generate();
+    let doe_user_id = Identifier::Account(doe_user.clone());
+
+    let contract_id = env.register_contract(None, OrganizationContract);
+    let contract_client = OrganizationContractClient::new(&env, &contract_id);
+
+    let (token_id, token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    let items = [(symbol!("talk"), 35), (symbol!("blog_post"), 25)];
+    let rewards: Map<Symbol, u32> = Map::from_array(&env, items);
+
+    contract_client.initialize(&admin_id, &symbol!("Kommit"), &rewards, &1000, &token_id);
+
+    add_member(&env, &contract_client, &contract_id, &admin_id, &admin_sign, &doe_user);
+
+    let nonce = contract_client.nonce(&admin_id);
+    let suspend_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("suspend"),
+        (&admin_id, &nonce, &doe_user, &0u64),
+    );
+    contract_client.suspend(&suspend_sign, &nonce, &doe_user, &0);
+
+    let nonce = token_client.nonce(&admin_id);
+    let xfer_approval_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &token_id,
+        symbol!("xfer"),
+        (&admin_id, &nonce, &doe_user_id, &BigInt::from_u32(&env, 35)),
+    );
+
+    contract_client.reward_m(&xfer_approval_sign, &doe_user, &symbol!("talk"));
+}
+
+#[test]
+#[should_panic(expected = "The user account you're trying to reward is an alumni member")]
+fn reward_alumni_member() {
+    let env = Env::default();
+
+    let (admin_id, admin_sign) = ed25519::generate(&env);
+
+    let doe_user = env.accounts().generate();
+    let doe_user_id = Identifier::Account(doe_user.clone());
+
+    let contract_id = env.register_contract(None, OrganizationContract);
+    let contract_client = OrganizationContractClient::new(&env, &contract_id);
+
+    let (token_id, token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    let items = [(symbol!("talk"), 35), (symbol!("blog_post"), 25)];
+    let rewards: Map<Symbol, u32> = Map::from_array(&env, items);
+
+    contract_client.initialize(&admin_id, &symbol!("Kommit"), &rewards, &1000, &token_id);
+
+    add_member(&env, &contract_client, &contract_id, &admin_id, &admin_sign, &doe_user);
+
+    let nonce = contract_client.nonce(&admin_id);
+    let status_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("set_status"),
+        (&admin_id, &nonce, &doe_user, &MemberStatus::Alumni),
+    );
+    contract_client.set_status(&status_sign, &nonce, &doe_user, &MemberStatus::Alumni);
+
+    let nonce = token_client.nonce(&admin_id);
+    let xfer_approval_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &token_id,
+        symbol!("xfer"),
+        (&admin_id, &nonce, &doe_user_id, &BigInt::from_u32(&env, 35)),
+    );
+
+    contract_client.reward_m(&xfer_approval_sign, &doe_user, &symbol!("talk"));
+}
+
+#[test]
+#[should_panic(expected = "Only the organization's admin can approve rewards")]
+fn members_cannot_reward_themselves() {
+    let env = Env::default();
+
+    let (admin_id, admin_sign) = ed25519::generate(&env);
+
+    let doe_user = env.accounts().generate();
+
+    let contract_id = env.register_contract(None, OrganizationContract);
+    let contract_client = OrganizationContractClient::new(&env, &contract_id);
+
+    let (token_id, _token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    let items = [(symbol!("talk"), 35), (symbol!("blog_post"), 25)];
+    let rewards: Map<Symbol, u32> = Map::from_array(&env, items);
+
+    contract_client.initialize(&admin_id, &symbol!("Kommit"), &rewards, &1000, &token_id);
+
+    add_member(&env, &contract_client, &contract_id, &admin_id, &admin_sign, &doe_user);
+
+    contract_client.with_source_account(&doe_user).reward_m(&Signature::Invoker, &doe_user, &symbol!("talk"));
+}
+
+#[test]
+fn suspensions_expire_and_can_be_lifted() {
+    let env = Env::default();
+
+    env.ledger().set(LedgerInfo {
+        timestamp: 1000,
+        protocol_version: 1,
+        sequence_number: 10,
+        network_passphrase: Default::default(),
+        base_reserve: 10,
+    });
+
+    let (admin_id, admin_sign) = ed25519::generate(&env);
+
+    let member = env.accounts().generate();
+    let doe_user = env.accounts().generate();
+
+    let contract_id = env.register_contract(None, OrganizationContract);
+    let contract_client = OrganizationContractClient::new(&env, &contract_id);
+
+    let (token_id, _token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    let items = [(symbol!("talk"), 35), (symbol!("blog_post"), 25)];
+    let rewards: Map<Symbol, u32> = Map::from_array(&env, items);
+
+    contract_client.initialize(&admin_id, &symbol!("Kommit"), &rewards, &1000, &token_id);
+
+    add_member(&env, &contract_client, &contract_id, &admin_id, &admin_sign, &member);
+    add_member(&env, &contract_client, &contract_id, &admin_id, &admin_sign, &doe_user);
+
+    let nonce = contract_client.nonce(&admin_id);
+    let suspend_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("suspend"),
+        (&admin_id, &nonce, &member, &2000u64),
+    );
+    contract_client.suspend(&suspend_sign, &nonce, &member, &2000);
+
+    let nonce = contract_client.nonce(&admin_id);
+    let suspend_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("suspend"),
+        (&admin_id, &nonce, &doe_user, &0u64),
+    );
+    contract_client.suspend(&suspend_sign, &nonce, &doe_user, &0);
+
+    assert_eq!(contract_client.get_member(&member).status, MemberStatus::Suspended, "Member is suspended");
+
+    env.ledger().set(LedgerInfo {
+        timestamp: 2000,
+        protocol_version: 1,
+        sequence_number: 20,
+        network_passphrase: Default::default(),
+        base_reserve: 10,
+    });
+
+    assert_eq!(contract_client.get_member(&member).status, MemberStatus::Active, "Suspension expired");
+    assert_eq!(contract_client.get_member(&doe_user).status, MemberStatus::Suspended, "Suspension without expiry stays");
+
+    let nonce = contract_client.nonce(&admin_id);
+    let reinstate_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("reinstate"),
+        (&admin_id, &nonce, &doe_user),
+    );
+    contract_client.reinstate(&reinstate_sign, &nonce, &doe_user);
+
+    assert_eq!(contract_client.get_member(&doe_user).status, MemberStatus::Active, "Member was reinstated");
+}
+
+#[test]
+fn members_level_up_through_tiers() {
+    let env = Env::default();
+
+    let (admin_id, admin_sign) = ed25519::generate(&env);
+
+    let member = env.accounts().generate();
+    let member_id = Identifier::Account(member.clone());
+
+    let contract_id = env.register_contract(None, OrganizationContract);
+    let contract_client = OrganizationContractClient::new(&env, &contract_id);
+
+    let (token_id, token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    let allowed_funds_to_issue = 1000;
+    let items = [(symbol!("talk"), 30), (symbol!("blog_post"), 25)];
+    let rewards: Map<Symbol, u32> = Map::from_array(&env, items);
+
+    contract_client.initialize(&admin_id, &symbol!("Kommit"), &rewards, &allowed_funds_to_issue, &token_id);
+
+    let nonce = token_client.nonce(&admin_id);
+    let approval_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &token_id,
+        symbol!("mint"),
+        (&admin_id, &nonce, &admin_id, &BigInt::from_u32(&env, allowed_funds_to_issue)),
+    );
+    contract_client.fund_c(&approval_sign);
+
+    let tiers = vec![
+        &env,
+        Tier { name: symbol!("contrib"), threshold: 0, multiplier: 100 },
+        Tier { name: symbol!("core"), threshold: 50, multiplier: 150 }
+    ];
+
+    let nonce = contract_client.nonce(&admin_id);
+    let tiers_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("set_tiers"),
+        (&admin_id, &nonce, &tiers),
+    );
+    contract_client.set_tiers(&tiers_sign, &nonce, &tiers);
+
+    add_member(&env, &contract_client, &contract_id, &admin_id, &admin_sign, &member);
+
+    for _ in 0..3 {
+        let reward_amount = contract_client.reward_amt(&member, &symbol!("talk"));
+        let nonce = token_client.nonce(&admin_id);
+        let xfer_approval_sign = ed25519::sign(
+            &env,
+            &admin_sign,
+            &token_id,
+            symbol!("xfer"),
+            (&admin_id, &nonce, &member_id, &BigInt::from_u32(&env, reward_amount)),
+        );
+
+        contract_client.reward_m(&xfer_approval_sign, &member, &symbol!("talk"));
+    }
+
+    assert_eq!(contract_client.get_tier(&member), 1, "Member was promoted to the core tier");
+    assert_eq!(contract_client.earned(&member), 105, "Lifetime rewards include the multiplier");
+    assert_eq!(
+        token_client.balance(&member_id),
+        BigInt::from_u32(&env, 105),
+        "The tier multiplier is applied after the promotion"
+    );
+}
+
+#[test]
+#[should_panic(expected = "The reward value is too large")]
+fn tier_multipliers_cannot_overflow_reward_values() {
+    let env = Env::default();
+
+    let (admin_id, admin_sign) = ed25519::generate(&env);
+
+    let member = env.accounts().generate();
+
+    let contract_id = env.register_contract(None, OrganizationContract);
+    let contract_client = OrganizationContractClient::new(&env, &contract_id);
+
+    let (token_id, _token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    let items = [(symbol!("talk"), u32::MAX)];
+    let rewards: Map<Symbol, u32> = Map::from_array(&env, items);
+
+    contract_client.initialize(&admin_id, &symbol!("Kommit"), &rewards, &1000, &token_id);
+
+    let tiers = vec![&env, Tier { name: symbol!("core"), threshold: 0, multiplier: 200 }];
+
+    let nonce = contract_client.nonce(&admin_id);
+    let tiers_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("set_tiers"),
+        (&admin_id, &nonce, &tiers),
+    );
+    contract_client.set_tiers(&tiers_sign, &nonce, &tiers);
+
+    add_member(&env, &contract_client, &contract_id, &admin_id, &admin_sign, &member);
+
+    contract_client.reward_amt(&member, &symbol!("talk"));
+}
+
+#[test]
+fn accounts_join_by_application_or_invite() {
+    let env = Env::default();
+
+    let (admin_id, admin_sign) = ed25519::generate(&env);
+
+    let applicant = env.accounts().generate();
+    let invited = env.accounts().generate();
+
+    let contract_id = env.register_contract(None, OrganizationContract);
+    let contract_client = OrganizationContractClient::new(&env, &contract_id);
+
+    let (token_id, _token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    let items = [(symbol!("talk"), 35), (symbol!("blog_post"), 25)];
+    let rewards: Map<Symbol, u32> = Map::from_array(&env, items);
+
+    contract_client.initialize(&admin_id, &symbol!("Kommit"), &rewards, &1000, &token_id);
+
+    // APPLICATION
+    let metadata = BytesN::from_array(&env, &[1; 32]);
+    contract_client.with_source_account(&applicant).apply(&metadata);
+
+    assert_eq!(contract_client.get_apps().len(), 1, "Application is pending");
+
+    let nonce = contract_client.nonce(&admin_id);
+    let accept_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("accept"),
+        (&admin_id, &nonce, &applicant),
+    );
+    contract_client.accept(&accept_sign, &nonce, &applicant);
+
+    assert_eq!(contract_client.get_apps().len(), 0, "Application was closed");
+    assert_eq!(contract_client.get_member(&applicant).status, MemberStatus::Active, "Applicant joined");
+
+    // INVITE CODE
+    let code = Bytes::from_slice(&env, b"welcome-2022");
+    let code_hash = env.compute_hash_sha256(code.clone());
+
+    let nonce = contract_client.nonce(&admin_id);
+    let invite_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("s_invite"),
+        (&admin_id, &nonce, &code_hash, &u64::MAX, &1u32),
+    );
+    contract_client.s_invite(&invite_sign, &nonce, &code_hash, &u64::MAX, &1);
+
+    contract_client.with_source_account(&invited).redeem(&code);
+
+    assert_eq!(contract_client.get_m(&0, &10).len(), 2, "Invited account joined");
+}
+
+#[test]
+#[should_panic(expected = "The invite code has no uses left")]
+fn invite_codes_have_a_use_limit() {
+    let env = Env::default();
+
+    let (admin_id, admin_sign) = ed25519::generate(&env);
+
+    let invited = env.accounts().generate();
+    let doe_user = env.accounts().generate();
+
+    let contract_id = env.register_contract(None, OrganizationContract);
+    let contract_client = OrganizationContractClient::new(&env, &contract_id);
+
+    let (token_id, _token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    let items = [(symbol!("talk"), 35), (symbol!("blog_post"), 25)];
+    let rewards: Map<Symbol, u32> = Map::from_array(&env, items);
+
+    contract_client.initialize(&admin_id, &symbol!("Kommit"), &rewards, &1000, &token_id);
+
+    let code = Bytes::from_slice(&env, b"welcome-2022");
+    let code_hash = env.compute_hash_sha256(code.clone());
+
+    let nonce = contract_client.nonce(&admin_id);
+    let invite_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("s_invite"),
+        (&admin_id, &nonce, &code_hash, &u64::MAX, &1u32),
+    );
+    contract_client.s_invite(&invite_sign, &nonce, &code_hash, &u64::MAX, &1);
+
+    contract_client.with_source_account(&invited).redeem(&code);
+    contract_client.with_source_account(&doe_user).redeem(&code);
+}
+
+#[test]
+#[should_panic(expected = "Only the organization's admin can perform this action")]
+fn strangers_cannot_add_members() {
+    let env = Env::default();
+
+    let (admin_id, _admin_sign) = ed25519::generate(&env);
+    let (stranger_id, stranger_sign) = ed25519::generate(&env);
+
+    let member = env.accounts().generate();
+
+    let contract_id = env.register_contract(None, OrganizationContract);
+    let contract_client = OrganizationContractClient::new(&env, &contract_id);
+
+    let (token_id, _token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    let items = [(symbol!("talk"), 35), (symbol!("blog_post"), 25)];
+    let rewards: Map<Symbol, u32> = Map::from_array(&env, items);
+
+    contract_client.initialize(&admin_id, &symbol!("Kommit"), &rewards, &1000, &token_id);
+
+    let nonce = contract_client.nonce(&stranger_id);
+    let add_sign = ed25519::sign(
+        &env,
+        &stranger_sign,
+        &contract_id,
+        symbol!("add_m"),
+        (&stranger_id, &nonce, &member),
+    );
+    contract_client.add_m(&add_sign, &nonce, &member);
+}
+
+#[test]
+fn rewards_credit_decaying_reputation() {
+    let env = Env::default();
+
+    env.ledger().set(LedgerInfo {
+        timestamp: 1000,
+        protocol_version: 1,
+        sequence_number: 10,
+        network_passphrase: Default::default(),
+        base_reserve: 10,
+    });
+
+    let (admin_id, admin_sign) = ed25519::generate(&env);
+
+    let member = env.accounts().generate();
+    let member_id = Identifier::Account(member.clone());
+
+    let doe_user = env.accounts().generate();
+    let doe_user_id = Identifier::Account(doe_user.clone());
+
+    let contract_id = env.register_contract(None, OrganizationContract);
+    let contract_client = OrganizationContractClient::new(&env, &contract_id);
+
+    let (token_id, token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    let allowed_funds_to_issue = 1000;
+    let items = [(symbol!("talk"), 30), (symbol!("blog_post"), 25)];
+    let rewards: Map<Symbol, u32> = Map::from_array(&env, items);
+
+    contract_client.initialize(&admin_id, &symbol!("Kommit"), &rewards, &allowed_funds_to_issue, &token_id);
+
+    let nonce = token_client.nonce(&admin_id);
+    let approval_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &token_id,
+        symbol!("mint"),
+        (&admin_id, &nonce, &admin_id, &BigInt::from_u32(&env, allowed_funds_to_issue)),
+    );
+    contract_client.fund_c(&approval_sign);
+
+    add_member(&env, &contract_client, &contract_id, &admin_id, &admin_sign, &doe_user);
+    add_member(&env, &contract_client, &contract_id, &admin_id, &admin_sign, &member);
+
+    let nonce = token_client.nonce(&admin_id);
+    let xfer_approval_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &token_id,
+        symbol!("xfer"),
+        (&admin_id, &nonce, &member_id, &BigInt::from_u32(&env, 30)),
+    );
+    contract_client.reward_m(&xfer_approval_sign, &member, &symbol!("talk"));
+
+    let nonce = token_client.nonce(&admin_id);
+    let xfer_approval_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &token_id,
+        symbol!("xfer"),
+        (&admin_id, &nonce, &doe_user_id, &BigInt::from_u32(&env, 25)),
+    );
+    contract_client.reward_m(&xfer_approval_sign, &doe_user, &symbol!("blog_post"));
+
+    let ranking = contract_client.rep_rank(&10);
+    assert_eq!(ranking.get(0).unwrap().unwrap().account, member, "Ranking is sorted by reputation");
+    assert_eq!(ranking.get(1).unwrap().unwrap().points, 25, "Reputation matches the rewards");
+
+    let nonce = contract_client.nonce(&admin_id);
+    let decay_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("s_decay"),
+        (&admin_id, &nonce, &50u32, &100u64),
+    );
+    contract_client.s_decay(&decay_sign, &nonce, &50, &100);
+
+    env.ledger().set(LedgerInfo {
+        timestamp: 1250,
+        protocol_version: 1,
+        sequence_number: 20,
+        network_passphrase: Default::default(),
+        base_reserve: 10,
+    });
+
+    assert_eq!(contract_client.rep(&member), 7, "Reputation decays on every elapsed period");
+
+    // Token transfers don't move reputation
+    token_client.with_source_account(&member).xfer(
+        &Signature::Invoker,
+        &BigInt::zero(&env),
+        &doe_user_id,
+        &BigInt::from_u32(&env, 30)
+    );
+    assert_eq!(contract_client.rep(&doe_user), 6, "Reputation isn't transferable");
+}
+
+#[test]
+fn members_earn_badges() {
+    let env = Env::default();
+
+    env.ledger().set(LedgerInfo {
+        timestamp: 1000,
+        protocol_version: 1,
+        sequence_number: 10,
+        network_passphrase: Default::default(),
+        base_reserve: 10,
+    });
+
+    let (admin_id, admin_sign) = ed25519::generate(&env);
+
+    let member = env.accounts().generate();
+    let member_id = Identifier::Account(member.clone());
+
+    let contract_id = env.register_contract(None, OrganizationContract);
+    let contract_client = OrganizationContractClient::new(&env, &contract_id);
+
+    let (token_id, token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    let allowed_funds_to_issue = 1000;
+    let items = [(symbol!("talk"), 30), (symbol!("review"), 10)];
+    let rewards: Map<Symbol, u32> = Map::from_array(&env, items);
+
+    contract_client.initialize(&admin_id, &symbol!("Kommit"), &rewards, &allowed_funds_to_issue, &token_id);
+
+    let nonce = token_client.nonce(&admin_id);
+    let approval_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &token_id,
+        symbol!("mint"),
+        (&admin_id, &nonce, &admin_id, &BigInt::from_u32(&env, allowed_funds_to_issue)),
+    );
+    contract_client.fund_c(&approval_sign);
+
+    let catalog = [
+        (symbol!("first_rwd"), BadgeRule::FirstRwd),
+        (symbol!("reviewer"), BadgeRule::RwdCount(RewardCount { r_type: symbol!("review"), count: 2 })),
+        (symbol!("one_year"), BadgeRule::Tenure(31536000)),
+        (symbol!("mentor"), BadgeRule::Manual),
+    ];
+
+    for (badge, rule) in catalog {
+        let nonce = contract_client.nonce(&admin_id);
+        let badge_sign = ed25519::sign(
+            &env,
+            &admin_sign,
+            &contract_id,
+            symbol!("s_badge"),
+            (&admin_id, &nonce, &badge, &rule),
+        );
+        contract_client.s_badge(&badge_sign, &nonce, &badge, &rule);
+    }
+
+    add_member(&env, &contract_client, &contract_id, &admin_id, &admin_sign, &member);
+
+    for _ in 0..2 {
+        let nonce = token_client.nonce(&admin_id);
+        let xfer_approval_sign = ed25519::sign(
+            &env,
+            &admin_sign,
+            &token_id,
+            symbol!("xfer"),
+            (&admin_id, &nonce, &member_id, &BigInt::from_u32(&env, 10)),
+        );
+        contract_client.reward_m(&xfer_approval_sign, &member, &symbol!("review"));
+    }
+
+    let badges = contract_client.badges(&member);
+    assert!(badges.contains(symbol!("first_rwd")), "Badge awarded on the first reward");
+    assert!(badges.contains(symbol!("reviewer")), "Badge awarded from the reward history");
+    assert!(!badges.contains(symbol!("one_year")), "Tenure badge isn't awarded yet");
+
+    let nonce = contract_client.nonce(&admin_id);
+    let award_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("award"),
+        (&admin_id, &nonce, &member, &symbol!("mentor")),
+    );
+    contract_client.award(&award_sign, &nonce, &member, &symbol!("mentor"));
+
+    env.ledger().set(LedgerInfo {
+        timestamp: 1000 + 31536000,
+        protocol_version: 1,
+        sequence_number: 20,
+        network_passphrase: Default::default(),
+        base_reserve: 10,
+    });
+    contract_client.check_b(&member);
+
+    assert_eq!(contract_client.badges(&member).len(), 4, "Member holds every badge");
+    assert!(contract_client.holders(&symbol!("mentor")).contains(&member), "Badge holders are tracked");
+}
+
+#[test]
+fn queries_work_while_paused() {
+    let env = Env::default();
+
+    let (contract_client, _admin, member, _doe_user) = create_paused_organization(&env);
+
+    assert!(contract_client.paused(), "Organization is paused");
+    assert_eq!(contract_client.get_m(&0, &10).len(), 2, "Members can be queried");
+    assert_eq!(contract_client.get_member(&member).status, MemberStatus::Active, "Profiles can be queried");
+    assert_eq!(contract_client.org_name(), symbol!("Kommit"), "Organization name can be queried");
+}
+
+#[test]
+#[should_panic(expected = "The organization is paused")]
+fn fund_contract_while_paused() {
+    let env = Env::default();
+
+    let (contract_client, _admin, _member, _doe_user) = create_paused_organization(&env);
+
+    contract_client.fund_c(&Signature::Invoker);
+}
+
+#[test]
+#[should_panic(expected = "The organization is paused")]
+fn reward_member_while_paused() {
+    let env = Env::default();
+
+    let (contract_client, _admin, member, _doe_user) = create_paused_organization(&env);
+
+    contract_client.reward_m(&Signature::Invoker, &member, &symbol!("talk"));
+}
+
+#[test]
+#[should_panic(expected = "The organization is paused")]
+fn revoke_member_while_paused() {
+    let env = Env::default();
+
+    let (contract_client, admin, member, _doe_user) = create_paused_organization(&env);
+
+    contract_client.with_source_account(&admin).revoke_m(&Signature::Invoker, &BigInt::zero(&env), &member);
+}
+
+#[test]
+#[should_panic(expected = "The organization is paused")]
+fn send_kudos_while_paused() {
+    let env = Env::default();
+
+    let (contract_client, _admin, member, doe_user) = create_paused_organization(&env);
+
+    contract_client.with_source_account(&member).kudos(&doe_user, &1, &symbol!("review"));
+}
+
+#[test]
+#[should_panic(expected = "The organization is paused")]
+fn team_reward_while_paused() {
+    let env = Env::default();
+
+    let (contract_client, _admin, member, doe_user) = create_paused_organization(&env);
+
+    contract_client.with_source_account(&member).team_rwd(&symbol!("devops"), &doe_user, &symbol!("talk"));
+}
+
+#[test]
+#[should_panic(expected = "The organization is paused")]
+fn open_stream_while_paused() {
+    let env = Env::default();
+
+    let (contract_client, admin, member, _doe_user) = create_paused_organization(&env);
+
+    contract_client.with_source_account(&admin).open_s(&Signature::Invoker, &BigInt::zero(&env), &member, &2, &1000, &1100);
+}
+
+#[test]
+#[should_panic(expected = "The organization is paused")]
+fn withdraw_stream_while_paused() {
+    let env = Env::default();
+
+    let (contract_client, _admin, member, _doe_user) = create_paused_organization(&env);
+
+    contract_client.with_source_account(&member).withdraw_s(&0);
+}
+
+#[test]
+#[should_panic(expected = "The organization is paused")]
+fn cancel_stream_while_paused() {
+    let env = Env::default();
+
+    let (contract_client, admin, _member, _doe_user) = create_paused_organization(&env);
+
+    contract_client.with_source_account(&admin).cancel_s(&Signature::Invoker, &BigInt::zero(&env), &0);
+}
+
+#[test]
+#[should_panic(expected = "The organization is paused")]
+fn open_epoch_while_paused() {
+    let env = Env::default();
+
+    let (contract_client, admin, _member, _doe_user) = create_paused_organization(&env);
+
+    contract_client.with_source_account(&admin).open_ep(&Signature::Invoker, &BigInt::zero(&env), &100, &2000, &3000);
+}
+
+#[test]
+#[should_panic(expected = "The organization is paused")]
+fn finalize_epoch_while_paused() {
+    let env = Env::default();
+
+    let (contract_client, _admin, member, _doe_user) = create_paused_organization(&env);
+
+    contract_client.with_source_account(&member).finalize(&0);
+}
+
+#[test]
+#[should_panic(expected = "The organization is paused")]
+fn claim_epoch_while_paused() {
+    let env = Env::default();
+
+    let (contract_client, _admin, member, _doe_user) = create_paused_organization(&env);
+
+    contract_client.with_source_account(&member).claim_ep(&0);
+}
+
+#[test]
+#[should_panic(expected = "The organization is paused")]
+fn sweep_epoch_while_paused() {
+    let env = Env::default();
+
+    let (contract_client, admin, _member, _doe_user) = create_paused_organization(&env);
+
+    contract_client.with_source_account(&admin).sweep_ep(&Signature::Invoker, &BigInt::zero(&env), &0);
+}
+
+#[test]
+#[should_panic(expected = "The organization is paused")]
+fn post_bounty_while_paused() {
+    let env = Env::default();
+
+    let (contract_client, admin, member, _doe_user) = create_paused_organization(&env);
+
+    let description = BytesN::from_array(&env, &[1; 32]);
+
+    contract_client.with_source_account(&admin).post_b(&Signature::Invoker, &BigInt::zero(&env), &description, &100, &2000, &member);
+}
+
+#[test]
+#[should_panic(expected = "The organization is paused")]
+fn release_bounty_while_paused() {
+    let env = Env::default();
+
+    let (contract_client, _admin, member, _doe_user) = create_paused_organization(&env);
+
+    contract_client.with_source_account(&member).release_b(&0);
+}
+
+#[test]
+#[should_panic(expected = "The organization is paused")]
+fn cancel_bounty_while_paused() {
+    let env = Env::default();
+
+    let (contract_client, admin, _member, _doe_user) = create_paused_organization(&env);
+
+    contract_client.with_source_account(&admin).cancel_b(&Signature::Invoker, &BigInt::zero(&env), &0);
+}
+
+#[test]
+#[should_panic(expected = "The organization is paused")]
+fn expire_bounty_while_paused() {
+    let env = Env::default();
+
+    let (contract_client, _admin, member, _doe_user) = create_paused_organization(&env);
+
+    contract_client.with_source_account(&member).expire_b(&0);
+}
+
+#[test]
+#[should_panic(expected = "The organization is paused")]
+fn reverse_reward_while_paused() {
+    let env = Env::default();
+
+    let (contract_client, admin, _member, _doe_user) = create_paused_organization(&env);
+
+    contract_client.with_source_account(&admin).reverse(&Signature::Invoker, &BigInt::zero(&env), &0);
+}
+
+#[test]
+#[should_panic(expected = "The organization is already initialized")]
+fn initialize_twice() {
+    let env = Env::default();
+
+    let (admin_id, _admin_sign) = ed25519::generate(&env);
+    let (stranger_id, _stranger_sign) = ed25519::generate(&env);
+
+    let contract_id = env.register_contract(None, OrganizationContract);
+    let contract_client = OrganizationContractClient::new(&env, &contract_id);
+
+    let (token_id, _token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    let items = [(symbol!("talk"), 35), (symbol!("blog_post"), 25)];
+    let rewards: Map<Symbol, u32> = Map::from_array(&env, items);
+
+    contract_client.initialize(&admin_id, &symbol!("Kommit"), &rewards, &1000, &token_id);
+    contract_client.initialize(&stranger_id, &symbol!("Kommit"), &rewards, &1000, &token_id);
+}
+
+#[test]
+fn team_managers_reward_within_their_budget() {
+    let env = Env::default();
+
+    let (admin_id, admin_sign) = ed25519::generate(&env);
+
+    let manager = env.accounts().generate();
+    let member = env.accounts().generate();
+    let member_id = Identifier::Account(member.clone());
+
+    let contract_id = env.register_contract(None, OrganizationContract);
+    let contract_client = OrganizationContractClient::new(&env, &contract_id);
+
+    let (token_id, token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    let allowed_funds_to_issue = 1000;
+    let items = [(symbol!("talk"), 30), (symbol!("blog_post"), 25)];
+    let rewards: Map<Symbol, u32> = Map::from_array(&env, items);
+
+    contract_client.initialize(&admin_id, &symbol!("Kommit"), &rewards, &allowed_funds_to_issue, &token_id);
+
+    let nonce = token_client.nonce(&admin_id);
+    let approval_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &token_id,
+        symbol!("mint"),
+        (&admin_id, &nonce, &admin_id, &BigInt::from_u32(&env, allowed_funds_to_issue)),
+    );
+    contract_client.fund_c(&approval_sign);
+
+    // Team rewards are paid from the treasury through an allowance
+    let nonce = token_client.nonce(&admin_id);
+    let allowance = BigInt::from_u32(&env, 100);
+    let allowance_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &token_id,
+        symbol!("approve"),
+        (&admin_id, &nonce, &Identifier::Contract(contract_id.clone()), &allowance),
+    );
+    token_client.approve(&allowance_sign, &nonce, &Identifier::Contract(contract_id.clone()), &allowance);
+
+    add_member(&env, &contract_client, &contract_id, &admin_id, &admin_sign, &manager);
+    add_member(&env, &contract_client, &contract_id, &admin_id, &admin_sign, &member);
+
+    let nonce = contract_client.nonce(&admin_id);
+    let team_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("add_team"),
+        (&admin_id, &nonce, &symbol!("devops"), &manager, &50u32, &604800u64),
+    );
+    contract_client.add_team(&team_sign, &nonce, &symbol!("devops"), &manager, &50, &604800);
+
+    let nonce = contract_client.nonce(&admin_id);
+    let team_add_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("team_add"),
+        (&admin_id, &nonce, &symbol!("devops"), &member),
+    );
+    contract_client.team_add(&team_add_sign, &nonce, &symbol!("devops"), &member);
+
+    contract_client.with_source_account(&manager).team_rwd(&symbol!("devops"), &member, &symbol!("talk"));
+
+    assert_eq!(contract_client.team_spent(&symbol!("devops")).total, 30, "Team spending is tracked");
+    assert_eq!(contract_client.team_left(&symbol!("devops")), 20, "Team budget is consumed");
+    assert_eq!(
+        token_client.balance(&member_id),
+        BigInt::from_u32(&env, 30),
+        "Team rewards are paid from the treasury"
+    );
+}
+
+#[test]
+#[should_panic(expected = "Only the team manager can reward the team members")]
+fn only_managers_reward_their_team() {
+    let env = Env::default();
+
+    let (admin_id, admin_sign) = ed25519::generate(&env);
+
+    let manager = env.accounts().generate();
+    let member = env.accounts().generate();
+
+    let contract_id = env.register_contract(None, OrganizationContract);
+    let contract_client = OrganizationContractClient::new(&env, &contract_id);
+
+    let (token_id, _token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    let items = [(symbol!("talk"), 30), (symbol!("blog_post"), 25)];
+    let rewards: Map<Symbol, u32> = Map::from_array(&env, items);
+
+    contract_client.initialize(&admin_id, &symbol!("Kommit"), &rewards, &1000, &token_id);
+
+    add_member(&env, &contract_client, &contract_id, &admin_id, &admin_sign, &manager);
+    add_member(&env, &contract_client, &contract_id, &admin_id, &admin_sign, &member);
+
+    let nonce = contract_client.nonce(&admin_id);
+    let team_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("add_team"),
+        (&admin_id, &nonce, &symbol!("devops"), &manager, &50u32, &604800u64),
+    );
+    contract_client.add_team(&team_sign, &nonce, &symbol!("devops"), &manager, &50, &604800);
+
+    contract_client.with_source_account(&member).team_rwd(&symbol!("devops"), &member, &symbol!("talk"));
+}
+
+#[test]
+#[should_panic(expected = "Only active team managers can reward the team members")]
+fn suspended_managers_cannot_reward_their_team() {
+    let env = Env::default();
+
+    let (admin_id, admin_sign) = ed25519::generate(&env);
+
+    let manager = env.accounts().generate();
+    let member = env.accounts().generate();
+
+    let contract_id = env.register_contract(None, OrganizationContract);
+    let contract_client = OrganizationContractClient::new(&env, &contract_id);
+
+    let (token_id, _token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    let items = [(symbol!("talk"), 30), (symbol!("blog_post"), 25)];
+    let rewards: Map<Symbol, u32> = Map::from_array(&env, items);
+
+    contract_client.initialize(&admin_id, &symbol!("Kommit"), &rewards, &1000, &token_id);
+
+    add_member(&env, &contract_client, &contract_id, &admin_id, &admin_sign, &manager);
+    add_member(&env, &contract_client, &contract_id, &admin_id, &admin_sign, &member);
+
+    let nonce = contract_client.nonce(&admin_id);
+    let team_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("add_team"),
+        (&admin_id, &nonce, &symbol!("devops"), &manager, &50u32, &604800u64),
+    );
+    contract_client.add_team(&team_sign, &nonce, &symbol!("devops"), &manager, &50, &604800);
+
+    let nonce = contract_client.nonce(&admin_id);
+    let suspend_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("suspend"),
+        (&admin_id, &nonce, &manager, &0u64),
+    );
+    contract_client.suspend(&suspend_sign, &nonce, &manager, &0);
+
+    contract_client.with_source_account(&manager).team_rwd(&symbol!("devops"), &member, &symbol!("talk"));
+}
+
+#[test]
+fn members_withdraw_from_reward_streams() {
+    let env = Env::default();
+
+    env.ledger().set(LedgerInfo {
+        timestamp: 1000,
+        protocol_version: 1,
+        sequence_number: 10,
+        network_passphrase: Default::default(),
+        base_reserve: 10,
+    });
+
+    let (admin_id, admin_sign) = ed25519::generate(&env);
+
+    let member = env.accounts().generate();
+    let member_id = Identifier::Account(member.clone());
+
+    let contract_id = env.register_contract(None, OrganizationContract);
+    let contract_client = OrganizationContractClient::new(&env, &contract_id);
+
+    let (token_id, token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    let allowed_funds_to_issue = 1000;
+    let items = [(symbol!("talk"), 30), (symbol!("blog_post"), 25)];
+    let rewards: Map<Symbol, u32> = Map::from_array(&env, items);
+
+    contract_client.initialize(&admin_id, &symbol!("Kommit"), &rewards, &allowed_funds_to_issue, &token_id);
+
+    let nonce = token_client.nonce(&admin_id);
+    let approval_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &token_id,
+        symbol!("mint"),
+        (&admin_id, &nonce, &admin_id, &BigInt::from_u32(&env, allowed_funds_to_issue)),
+    );
+    contract_client.fund_c(&approval_sign);
+
+    // Streams are escrowed from the treasury through an allowance
+    let nonce = token_client.nonce(&admin_id);
+    let allowance = BigInt::from_u32(&env, 200);
+    let allowance_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &token_id,
+        symbol!("approve"),
+        (&admin_id, &nonce, &Identifier::Contract(contract_id.clone()), &allowance),
+    );
+    token_client.approve(&allowance_sign, &nonce, &Identifier::Contract(contract_id.clone()), &allowance);
+
+    add_member(&env, &contract_client, &contract_id, &admin_id, &admin_sign, &member);
+
+    let nonce = contract_client.nonce(&admin_id);
+    let stream_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("open_s"),
+        (&admin_id, &nonce, &member, &2u32, &1000u64, &1100u64),
+    );
+    let stream_id = contract_client.open_s(&stream_sign, &nonce, &member, &2, &1000, &1100);
+
+    assert_eq!(contract_client.get_s(&stream_id).total, 200, "Stream total is escrowed");
+
+    env.ledger().set(LedgerInfo {
+        timestamp: 1050,
+        protocol_version: 1,
+        sequence_number: 20,
+        network_passphrase: Default::default(),
+        base_reserve: 10,
+    });
+
+    assert_eq!(contract_client.s_balance(&stream_id), 100, "Half of the stream was accrued");
+
+    contract_client.with_source_account(&member).withdraw_s(&stream_id);
+
+    assert_eq!(
+        token_client.balance(&member_id),
+        BigInt::from_u32(&env, 100),
+        "Member withdraws the accrued amount"
+    );
+
+    env.ledger().set(LedgerInfo {
+        timestamp: 1075,
+        protocol_version: 1,
+        sequence_number: 30,
+        network_passphrase: Default::default(),
+        base_reserve: 10,
+    });
+
+    let nonce = contract_client.nonce(&admin_id);
+    let cancel_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("cancel_s"),
+        (&admin_id, &nonce, &stream_id),
+    );
+    contract_client.cancel_s(&cancel_sign, &nonce, &stream_id);
+
+    assert_eq!(
+        token_client.balance(&member_id),
+        BigInt::from_u32(&env, 150),
+        "Cancelling settles the accrued amount"
+    );
+    assert_eq!(
+        token_client.balance(&admin_id),
+        BigInt::from_u32(&env, 850),
+        "The unstreamed remainder returns to the treasury"
+    );
+}
+
+#[test]
+fn revoking_membership_closes_streams() {
+    let env = Env::default();
+
+    env.ledger().set(LedgerInfo {
+        timestamp: 1000,
+        protocol_version: 1,
+        sequence_number: 10,
+        network_passphrase: Default::default(),
+        base_reserve: 10,
+    });
+
+    let (admin_id, admin_sign) = ed25519::generate(&env);
+
+    let member = env.accounts().generate();
+    let member_id = Identifier::Account(member.clone());
+
+    let contract_id = env.register_contract(None, OrganizationContract);
+    let contract_client = OrganizationContractClient::new(&env, &contract_id);
+
+    let (token_id, token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    let allowed_funds_to_issue = 1000;
+    let items = [(symbol!("talk"), 30), (symbol!("blog_post"), 25)];
+    let rewards: Map<Symbol, u32> = Map::from_array(&env, items);
+
+    contract_client.initialize(&admin_id, &symbol!("Kommit"), &rewards, &allowed_funds_to_issue, &token_id);
+
+    let nonce = token_client.nonce(&admin_id);
+    let approval_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &token_id,
+        symbol!("mint"),
+        (&admin_id, &nonce, &admin_id, &BigInt::from_u32(&env, allowed_funds_to_issue)),
+    );
+    contract_client.fund_c(&approval_sign);
+
+    let nonce = token_client.nonce(&admin_id);
+    let allowance = BigInt::from_u32(&env, 200);
+    let allowance_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &token_id,
+        symbol!("approve"),
+        (&admin_id, &nonce, &Identifier::Contract(contract_id.clone()), &allowance),
+    );
+    token_client.approve(&allowance_sign, &nonce, &Identifier::Contract(contract_id.clone()), &allowance);
+
+    add_member(&env, &contract_client, &contract_id, &admin_id, &admin_sign, &member);
+
+    let nonce = contract_client.nonce(&admin_id);
+    let stream_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("open_s"),
+        (&admin_id, &nonce, &member, &2u32, &1000u64, &1100u64),
+    );
+    let stream_id = contract_client.open_s(&stream_sign, &nonce, &member, &2, &1000, &1100);
+
+    env.ledger().set(LedgerInfo {
+        timestamp: 1050,
+        protocol_version: 1,
+        sequence_number: 20,
+        network_passphrase: Default::default(),
+        base_reserve: 10,
+    });
+
+    token_client.with_source_account(&member).approve(
+        &Signature::Invoker,
+        &BigInt::zero(&env),
+        &Identifier::Contract(contract_id.clone()),
+        &token_client.balance(&member_id)
+    );
+
+    let nonce = contract_client.nonce(&admin_id);
+    let revoke_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("revoke_m"),
+        (&admin_id, &nonce, &member),
+    );
+    contract_client.revoke_m(&revoke_sign, &nonce, &member);
+
+    assert!(!contract_client.get_s(&stream_id).active, "Stream was closed");
+    assert_eq!(
+        token_client.balance(&member_id),
+        BigInt::from_u32(&env, 100),
+        "The streamed amount is paid to the member"
+    );
+    assert_eq!(
+        token_client.balance(&admin_id),
+        BigInt::from_u32(&env, 900),
+        "The unstreamed remainder returns to the treasury"
+    );
+}
+
+#[test]
+fn epoch_pool_is_split_by_points() {
+    let env = Env::default();
+
+    env.ledger().set(LedgerInfo {
+        timestamp: 1000,
+        protocol_version: 1,
+        sequence_number: 10,
+        network_passphrase: Default::default(),
+        base_reserve: 10,
+    });
+
+    let (admin_id, admin_sign) = ed25519::generate(&env);
+
+    let member = env.accounts().generate();
+    let member_id = Identifier::Account(member.clone());
+
+    let doe_user = env.accounts().generate();
+    let doe_user_id = Identifier::Account(doe_user.clone());
+
+    let contract_id = env.register_contract(None, OrganizationContract);
+    let contract_client = OrganizationContractClient::new(&env, &contract_id);
+
+    let (token_id, token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    let allowed_funds_to_issue = 1000;
+    let items = [(symbol!("talk"), 30), (symbol!("review"), 10)];
+    let rewards: Map<Symbol, u32> = Map::from_array(&env, items);
+
+    contract_client.initialize(&admin_id, &symbol!("Kommit"), &rewards, &allowed_funds_to_issue, &token_id);
+
+    let nonce = token_client.nonce(&admin_id);
+    let approval_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &token_id,
+        symbol!("mint"),
+        (&admin_id, &nonce, &admin_id, &BigInt::from_u32(&env, allowed_funds_to_issue)),
+    );
+    contract_client.fund_c(&approval_sign);
+
+    // The epoch pool is escrowed from the treasury through an allowance
+    let nonce = token_client.nonce(&admin_id);
+    let allowance = BigInt::from_u32(&env, 100);
+    let allowance_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &token_id,
+        symbol!("approve"),
+        (&admin_id, &nonce, &Identifier::Contract(contract_id.clone()), &allowance),
+    );
+    token_client.approve(&allowance_sign, &nonce, &Identifier::Contract(contract_id.clone()), &allowance);
+
+    add_member(&env, &contract_client, &contract_id, &admin_id, &admin_sign, &member);
+    add_member(&env, &contract_client, &contract_id, &admin_id, &admin_sign, &doe_user);
+
+    let nonce = contract_client.nonce(&admin_id);
+    let epoch_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("open_ep"),
+        (&admin_id, &nonce, &100u64, &2000u64, &3000u64),
+    );
+    let epoch = contract_client.open_ep(&epoch_sign, &nonce, &100, &2000, &3000);
+
+    let accruals = [(member.clone(), symbol!("talk")), (doe_user.clone(), symbol!("review"))];
+
+    for (account, reward_type) in accruals {
+        let nonce = contract_client.nonce(&admin_id);
+        let accrue_sign = ed25519::sign(
+            &env,
+            &admin_sign,
+            &contract_id,
+            symbol!("accrue"),
+            (&admin_id, &nonce, &account, &reward_type),
+        );
+        contract_client.accrue(&accrue_sign, &nonce, &account, &reward_type);
+    }
+
+    assert_eq!(contract_client.get_ep(&epoch).points, 40, "Points accrue per reward type");
+
+    env.ledger().set(LedgerInfo {
+        timestamp: 2000,
+        protocol_version: 1,
+        sequence_number: 20,
+        network_passphrase: Default::default(),
+        base_reserve: 10,
+    });
+
+    contract_client.with_source_account(&doe_user).finalize(&epoch);
+
+    contract_client.with_source_account(&member).claim_ep(&epoch);
+    contract_client.with_source_account(&doe_user).claim_ep(&epoch);
+
+    assert_eq!(token_client.balance(&member_id), BigInt::from_u32(&env, 75), "Share matches the points");
+    assert_eq!(token_client.balance(&doe_user_id), BigInt::from_u32(&env, 25), "Share matches the points");
+}
+
+#[test]
+fn unclaimed_epoch_shares_are_swept() {
+    let env = Env::default();
+
+    env.ledger().set(LedgerInfo {
+        timestamp: 1000,
+        protocol_version: 1,
+        sequence_number: 10,
+        network_passphrase: Default::default(),
+        base_reserve: 10,
+    });
+
+    let (admin_id, admin_sign) = ed25519::generate(&env);
+
+    let member = env.accounts().generate();
+    let member_id = Identifier::Account(member.clone());
+
+    let doe_user = env.accounts().generate();
+
+    let contract_id = env.register_contract(None, OrganizationContract);
+    let contract_client = OrganizationContractClient::new(&env, &contract_id);
+
+    let (token_id, token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    let allowed_funds_to_issue = 1000;
+    let items = [(symbol!("talk"), 30), (symbol!("review"), 40)];
+    let rewards: Map<Symbol, u32> = Map::from_array(&env, items);
+
+    contract_client.initialize(&admin_id, &symbol!("Kommit"), &rewards, &allowed_funds_to_issue, &token_id);
+
+    let nonce = token_client.nonce(&admin_id);
+    let approval_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &token_id,
+        symbol!("mint"),
+        (&admin_id, &nonce, &admin_id, &BigInt::from_u32(&env, allowed_funds_to_issue)),
+    );
+    contract_client.fund_c(&approval_sign);
+
+    // The epoch pool is escrowed from the treasury through an allowance
+    let nonce = token_client.nonce(&admin_id);
+    let allowance = BigInt::from_u32(&env, 100);
+    let allowance_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &token_id,
+        symbol!("approve"),
+        (&admin_id, &nonce, &Identifier::Contract(contract_id.clone()), &allowance),
+    );
+    token_client.approve(&allowance_sign, &nonce, &Identifier::Contract(contract_id.clone()), &allowance);
+
+    add_member(&env, &contract_client, &contract_id, &admin_id, &admin_sign, &member);
+    add_member(&env, &contract_client, &contract_id, &admin_id, &admin_sign, &doe_user);
+
+    let nonce = contract_client.nonce(&admin_id);
+    let epoch_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("open_ep"),
+        (&admin_id, &nonce, &100u64, &2000u64, &3000u64),
+    );
+    let epoch = contract_client.open_ep(&epoch_sign, &nonce, &100, &2000, &3000);
+
+    let accruals = [(member.clone(), symbol!("talk")), (doe_user.clone(), symbol!("review"))];
+
+    for (account, reward_type) in accruals {
+        let nonce = contract_client.nonce(&admin_id);
+        let accrue_sign = ed25519::sign(
+            &env,
+            &admin_sign,
+            &contract_id,
+            symbol!("accrue"),
+            (&admin_id, &nonce, &account, &reward_type),
+        );
+        contract_client.accrue(&accrue_sign, &nonce, &account, &reward_type);
+    }
+
+    env.ledger().set(LedgerInfo {
+        timestamp: 2000,
+        protocol_version: 1,
+        sequence_number: 20,
+        network_passphrase: Default::default(),
+        base_reserve: 10,
+    });
+
+    contract_client.with_source_account(&doe_user).finalize(&epoch);
+    contract_client.with_source_account(&member).claim_ep(&epoch);
+
+    assert_eq!(token_client.balance(&member_id), BigInt::from_u32(&env, 42), "Shares are rounded down");
+
+    env.ledger().set(LedgerInfo {
+        timestamp: 3000,
+        protocol_version: 1,
+        sequence_number: 30,
+        network_passphrase: Default::default(),
+        base_reserve: 10,
+    });
+
+    let nonce = contract_client.nonce(&admin_id);
+    let sweep_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("sweep_ep"),
+        (&admin_id, &nonce, &epoch),
+    );
+    contract_client.sweep_ep(&sweep_sign, &nonce, &epoch);
+
+    assert!(contract_client.get_ep(&epoch).swept, "Epoch was swept");
+    assert_eq!(
+        token_client.balance(&admin_id),
+        BigInt::from_u32(&env, 958),
+        "The unclaimed share and the rounding dust return to the treasury"
+    );
+}
+
+fn create_governed_organization(env: &Env) -> (OrganizationContractClient, AccountId, AccountId) {
+    let (admin_id, admin_sign) = ed25519::generate(&env);
+
+    let member = env.accounts().generate();
+    let doe_user = env.accounts().generate();
+
+    let contract_id = env.register_contract(None, OrganizationContract);
+    let contract_client = OrganizationContractClient::new(&env, &contract_id);
+
+    let (token_id, _token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    let items = [(symbol!("talk"), 30), (symbol!("blog_post"), 25)];
+    let rewards: Map<Symbol, u32> = Map::from_array(&env, items);
+
+    contract_client.initialize(&admin_id, &symbol!("Kommit"), &rewards, &1000, &token_id);
+
+    add_member(&env, &contract_client, &contract_id, &admin_id, &admin_sign, &member);
+    add_member(&env, &contract_client, &contract_id, &admin_id, &admin_sign, &doe_user);
+
+    let config = GovConfig { weight: VoteWeight::Member, quorum: 2, period: 100, delay: 50 };
+
+    let nonce = contract_client.nonce(&admin_id);
+    let gov_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("s_gov"),
+        (&admin_id, &nonce, &config),
+    );
+    contract_client.s_gov(&gov_sign, &nonce, &config);
+
+    (contract_client, member, doe_user)
+}
+
+#[test]
+fn members_change_the_reward_catalog() {
+    let env = Env::default();
+
+    env.ledger().set(LedgerInfo {
+        timestamp: 1000,
+        protocol_version: 1,
+        sequence_number: 10,
+        network_passphrase: Default::default(),
+        base_reserve: 10,
+    });
+
+    let (contract_client, member, doe_user) = create_governed_organization(&env);
+
+    let action = Action::SetReward(RewardValue { r_type: symbol!("talk"), value: 50 });
+    let proposal = contract_client.with_source_account(&member).propose(&action);
+
+    contract_client.with_source_account(&member).vote(&proposal, &true);
+    contract_client.with_source_account(&doe_user).vote(&proposal, &true);
+
+    assert_eq!(contract_client.get_prop(&proposal).votes_for, 2, "One member, one vote");
+
+    env.ledger().set(LedgerInfo {
+        timestamp: 1150,
+        protocol_version: 1,
+        sequence_number: 20,
+        network_passphrase: Default::default(),
+        base_reserve: 10,
+    });
+
+    contract_client.execute(&proposal);
+
+    assert_eq!(contract_client.get_rwds().get(symbol!("talk")).unwrap().unwrap(), 50, "Reward was updated");
+}
+
+#[test]
+#[should_panic(expected = "The proposal can't be executed yet")]
+fn proposals_are_timelocked() {
+    let env = Env::default();
+
+    env.ledger().set(LedgerInfo {
+        timestamp: 1000,
+        protocol_version: 1,
+        sequence_number: 10,
+        network_passphrase: Default::default(),
+        base_reserve: 10,
+    });
+
+    let (contract_client, member, doe_user) = create_governed_organization(&env);
+
+    let action = Action::RmReward(symbol!("blog_post"));
+    let proposal = contract_client.with_source_account(&member).propose(&action);
+
+    contract_client.with_source_account(&member).vote(&proposal, &true);
+    contract_client.with_source_account(&doe_user).vote(&proposal, &true);
+
+    env.ledger().set(LedgerInfo {
+        timestamp: 1100,
+        protocol_version: 1,
+        sequence_number: 20,
+        network_passphrase: Default::default(),
+        base_reserve: 10,
+    });
+
+    contract_client.execute(&proposal);
+}
+
+#[test]
+fn bounty_lifecycle() {
+    let env = Env::default();
+
+    env.ledger().set(LedgerInfo {
+        timestamp: 1000,
+        protocol_version: 1,
+        sequence_number: 10,
+        network_passphrase: Default::default(),
+        base_reserve: 10,
+    });
+
+    let (admin_id, admin_sign) = ed25519::generate(&env);
+
+    let member = env.accounts().generate();
+    let member_id = Identifier::Account(member.clone());
+
+    let reviewer = env.accounts().generate();
+
+    let contract_id = env.register_contract(None, OrganizationContract);
+    let contract_client = OrganizationContractClient::new(&env, &contract_id);
+
+    let (token_id, token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    let allowed_funds_to_issue = 1000;
+    let items = [(symbol!("talk"), 30), (symbol!("blog_post"), 25)];
+    let rewards: Map<Symbol, u32> = Map::from_array(&env, items);
+
+    contract_client.initialize(&admin_id, &symbol!("Kommit"), &rewards, &allowed_funds_to_issue, &token_id);
+
+    let nonce = token_client.nonce(&admin_id);
+    let approval_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &token_id,
+        symbol!("mint"),
+        (&admin_id, &nonce, &admin_id, &BigInt::from_u32(&env, allowed_funds_to_issue)),
+    );
+    contract_client.fund_c(&approval_sign);
+
+    // Bounties are escrowed from the treasury through an allowance
+    let nonce = token_client.nonce(&admin_id);
+    let allowance = BigInt::from_u32(&env, 200);
+    let allowance_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &token_id,
+        symbol!("approve"),
+        (&admin_id, &nonce, &Identifier::Contract(contract_id.clone()), &allowance),
+    );
+    token_client.approve(&allowance_sign, &nonce, &Identifier::Contract(contract_id.clone()), &allowance);
+
+    add_member(&env, &contract_client, &contract_id, &admin_id, &admin_sign, &member);
+
+    let description = BytesN::from_array(&env, &[1; 32]);
+
+    let nonce = contract_client.nonce(&admin_id);
+    let post_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("post_b"),
+        (&admin_id, &nonce, &description, &150u64, &2000u64, &reviewer),
+    );
+    let released_bounty = contract_client.post_b(&post_sign, &nonce, &description, &150, &2000, &reviewer);
+
+    let nonce = contract_client.nonce(&admin_id);
+    let post_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("post_b"),
+        (&admin_id, &nonce, &description, &50u64, &1500u64, &reviewer),
+    );
+    let expired_bounty = contract_client.post_b(&post_sign, &nonce, &description, &50, &1500, &reviewer);
+
+    assert_eq!(token_client.balance(&admin_id), BigInt::from_u32(&env, 800), "Bounties are escrowed");
+
+    contract_client.with_source_account(&member).claim_b(&released_bounty);
+    contract_client.with_source_account(&member).submit_b(&released_bounty, &BytesN::from_array(&env, &[2; 32]));
+
+    env.ledger().set(LedgerInfo {
+        timestamp: 2500,
+        protocol_version: 1,
+        sequence_number: 20,
+        network_passphrase: Default::default(),
+        base_reserve: 10,
+    });
+
+    contract_client.with_source_account(&reviewer).release_b(&released_bounty);
+    contract_client.expire_b(&expired_bounty);
+
+    assert_eq!(
+        contract_client.get_b(&released_bounty).state,
+        BountyState::Released(member.clone()),
+        "Bounty was released"
+    );
+    assert_eq!(token_client.balance(&member_id), BigInt::from_u32(&env, 150), "Claimant receives the escrow");
+    assert_eq!(token_client.balance(&admin_id), BigInt::from_u32(&env, 850), "Expired bounty returns to the treasury");
+}
+
+#[test]
+#[should_panic(expected = "Only the bounty's reviewer can release it")]
+fn only_reviewers_release_bounties() {
+    let env = Env::default();
+
+    let (admin_id, admin_sign) = ed25519::generate(&env);
+
+    let member = env.accounts().generate();
+    let reviewer = env.accounts().generate();
+
+    let contract_id = env.register_contract(None, OrganizationContract);
+    let contract_client = OrganizationContractClient::new(&env, &contract_id);
+
+    let (token_id, token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    let allowed_funds_to_issue = 1000;
+    let items = [(symbol!("talk"), 30), (symbol!("blog_post"), 25)];
+    let rewards: Map<Symbol, u32> = Map::from_array(&env, items);
+
+    contract_client.initialize(&admin_id, &symbol!("Kommit"), &rewards, &allowed_funds_to_issue, &token_id);
+
+    let nonce = token_client.nonce(&admin_id);
+    let approval_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &token_id,
+        symbol!("mint"),
+        (&admin_id, &nonce, &admin_id, &BigInt::from_u32(&env, allowed_funds_to_issue)),
+    );
+    contract_client.fund_c(&approval_sign);
+
+    let nonce = token_client.nonce(&admin_id);
+    let allowance = BigInt::from_u32(&env, 200);
+    let allowance_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &token_id,
+        symbol!("approve"),
+        (&admin_id, &nonce, &Identifier::Contract(contract_id.clone()), &allowance),
+    );
+    token_client.approve(&allowance_sign, &nonce, &Identifier::Contract(contract_id.clone()), &allowance);
+
+    add_member(&env, &contract_client, &contract_id, &admin_id, &admin_sign, &member);
+
+    let description = BytesN::from_array(&env, &[1; 32]);
+
+    let nonce = contract_client.nonce(&admin_id);
+    let post_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("post_b"),
+        (&admin_id, &nonce, &description, &150u64, &u64::MAX, &reviewer),
+    );
+    let bounty = contract_client.post_b(&post_sign, &nonce, &description, &150, &u64::MAX, &reviewer);
+
+    contract_client.with_source_account(&member).claim_b(&bounty);
+    contract_client.with_source_account(&member).submit_b(&bounty, &BytesN::from_array(&env, &[2; 32]));
+    contract_client.with_source_account(&member).release_b(&bounty);
+}
+
+#[test]
+fn reverse_reward_within_dispute_window() {
+    let env = Env::default();
+
+    env.ledger().set(LedgerInfo {
+        timestamp: 1000,
+        protocol_version: 1,
+        sequence_number: 10,
+        network_passphrase: Default::default(),
+        base_reserve: 10,
+    });
+
+    let (admin_id, admin_sign) = ed25519::generate(&env);
+
+    let member = env.accounts().generate();
+    let member_id = Identifier::Account(member.clone());
+
+    let contract_id = env.register_contract(None, OrganizationContract);
+    let contract_client = OrganizationContractClient::new(&env, &contract_id);
+
+    let (token_id, token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    let allowed_funds_to_issue = 1000;
+    let items = [(symbol!("talk"), 30), (symbol!("blog_post"), 25)];
+    let rewards: Map<Symbol, u32> = Map::from_array(&env, items);
+
+    contract_client.initialize(&admin_id, &symbol!("Kommit"), &rewards, &allowed_funds_to_issue, &token_id);
+
+    let nonce = token_client.nonce(&admin_id);
+    let approval_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &token_id,
+        symbol!("mint"),
+        (&admin_id, &nonce, &admin_id, &BigInt::from_u32(&env, allowed_funds_to_issue)),
+    );
+    contract_client.fund_c(&approval_sign);
+
+    let nonce = contract_client.nonce(&admin_id);
+    let dispute_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("s_dispute"),
+        (&admin_id, &nonce, &500u64),
+    );
+    contract_client.s_dispute(&dispute_sign, &nonce, &500);
+
+    add_member(&env, &contract_client, &contract_id, &admin_id, &admin_sign, &member);
+
+    let nonce = token_client.nonce(&admin_id);
+    let xfer_approval_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &token_id,
+        symbol!("xfer"),
+        (&admin_id, &nonce, &member_id, &BigInt::from_u32(&env, 30)),
+    );
+    let reward = contract_client.reward_m(&xfer_approval_sign, &member, &symbol!("talk"));
+
+    assert_eq!(contract_client.m_rwds(&member), vec![&env, reward], "Reward is kept in the member's history");
+    assert_eq!(contract_client.earned(&member), 30, "Reward is counted in the lifetime total");
+
+    // The member's payout address allows the contract to pull the tokens back
+    token_client.with_source_account(&member).approve(
+        &Signature::Invoker,
+        &BigInt::zero(&env),
+        &Identifier::Contract(contract_id.clone()),
+        &BigInt::from_u32(&env, 30),
+    );
+
+    env.ledger().set(LedgerInfo {
+        timestamp: 1200,
+        protocol_version: 1,
+        sequence_number: 20,
+        network_passphrase: Default::default(),
+        base_reserve: 10,
+    });
+
+    let nonce = contract_client.nonce(&admin_id);
+    let reverse_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("reverse"),
+        (&admin_id, &nonce, &reward),
+    );
+    contract_client.reverse(&reverse_sign, &nonce, &reward);
+
+    assert!(contract_client.get_rwd(&reward).reversed, "Reward is marked as reversed");
+    assert_eq!(contract_client.earned(&member), 0, "Reversal is removed from the lifetime total");
+    assert_eq!(contract_client.rep(&member), 0, "Reversal is removed from the reputation");
+    assert_eq!(token_client.balance(&member_id), BigInt::zero(&env), "Tokens are pulled back from the member");
+    assert_eq!(
+        token_client.balance(&admin_id),
+        BigInt::from_u32(&env, allowed_funds_to_issue),
+        "Tokens return to the treasury"
+    );
+}
+
+#[test]
+#[should_panic(expected = "The dispute window for this reward is over")]
+fn reverse_reward_after_dispute_window() {
+    let env = Env::default();
+
+    env.ledger().set(LedgerInfo {
+        timestamp: 1000,
+        protocol_version: 1,
+        sequence_number: 10,
+        network_passphrase: Default::default(),
+        base_reserve: 10,
+    });
+
+    let (admin_id, admin_sign) = ed25519::generate(&env);
+
+    let member = env.accounts().generate();
+    let member_id = Identifier::Account(member.clone());
+
+    let contract_id = env.register_contract(None, OrganizationContract);
+    let contract_client = OrganizationContractClient::new(&env, &contract_id);
+
+    let (token_id, token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    let allowed_funds_to_issue = 1000;
+    let items = [(symbol!("talk"), 30), (symbol!("blog_post"), 25)];
+    let rewards: Map<Symbol, u32> = Map::from_array(&env, items);
+
+    contract_client.initialize(&admin_id, &symbol!("Kommit"), &rewards, &allowed_funds_to_issue, &token_id);
+
+    let nonce = token_client.nonce(&admin_id);
+    let approval_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &token_id,
+        symbol!("mint"),
+        (&admin_id, &nonce, &admin_id, &BigInt::from_u32(&env, allowed_funds_to_issue)),
+    );
+    contract_client.fund_c(&approval_sign);
+
+    let nonce = contract_client.nonce(&admin_id);
+    let dispute_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("s_dispute"),
+        (&admin_id, &nonce, &500u64),
+    );
+    contract_client.s_dispute(&dispute_sign, &nonce, &500);
+
+    add_member(&env, &contract_client, &contract_id, &admin_id, &admin_sign, &member);
+
+    let nonce = token_client.nonce(&admin_id);
+    let xfer_approval_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &token_id,
+        symbol!("xfer"),
+        (&admin_id, &nonce, &member_id, &BigInt::from_u32(&env, 30)),
+    );
+    let reward = contract_client.reward_m(&xfer_approval_sign, &member, &symbol!("talk"));
+
+    env.ledger().set(LedgerInfo {
+        timestamp: 1500,
+        protocol_version: 1,
+        sequence_number: 20,
+        network_passphrase: Default::default(),
+        base_reserve: 10,
+    });
+
+    let nonce = contract_client.nonce(&admin_id);
+    let reverse_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("reverse"),
+        (&admin_id, &nonce, &reward),
+    );
+    contract_client.reverse(&reverse_sign, &nonce, &reward);
+}
+
+#[test]
+fn reversing_decayed_rewards_keeps_later_reputation() {
+    let env = Env::default();
+
+    env.ledger().set(LedgerInfo {
+        timestamp: 1000,
+        protocol_version: 1,
+        sequence_number: 10,
+        network_passphrase: Default::default(),
+        base_reserve: 10,
+    });
+
+    let (admin_id, admin_sign) = ed25519::generate(&env);
+
+    let member = env.accounts().generate();
+    let member_id = Identifier::Account(member.clone());
+
+    let contract_id = env.register_contract(None, OrganizationContract);
+    let contract_client = OrganizationContractClient::new(&env, &contract_id);
+
+    let (token_id, token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    let allowed_funds_to_issue = 1000;
+    let items = [(symbol!("talk"), 30), (symbol!("blog_post"), 25)];
+    let rewards: Map<Symbol, u32> = Map::from_array(&env, items);
+
+    contract_client.initialize(&admin_id, &symbol!("Kommit"), &rewards, &allowed_funds_to_issue, &token_id);
+
+    let nonce = token_client.nonce(&admin_id);
+    let approval_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &token_id,
+        symbol!("mint"),
+        (&admin_id, &nonce, &admin_id, &BigInt::from_u32(&env, allowed_funds_to_issue)),
+    );
+    contract_client.fund_c(&approval_sign);
+
+    let nonce = contract_client.nonce(&admin_id);
+    let dispute_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("s_dispute"),
+        (&admin_id, &nonce, &500u64),
+    );
+    contract_client.s_dispute(&dispute_sign, &nonce, &500);
+
+    let nonce = contract_client.nonce(&admin_id);
+    let decay_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("s_decay"),
+        (&admin_id, &nonce, &50u32, &100u64),
+    );
+    contract_client.s_decay(&decay_sign, &nonce, &50, &100);
+
+    add_member(&env, &contract_client, &contract_id, &admin_id, &admin_sign, &member);
+
+    let nonce = token_client.nonce(&admin_id);
+    let xfer_approval_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &token_id,
+        symbol!("xfer"),
+        (&admin_id, &nonce, &member_id, &BigInt::from_u32(&env, 30)),
+    );
+    let reward = contract_client.reward_m(&xfer_approval_sign, &member, &symbol!("talk"));
+
+    env.ledger().set(LedgerInfo {
+        timestamp: 1100,
+        protocol_version: 1,
+        sequence_number: 20,
+        network_passphrase: Default::default(),
+        base_reserve: 10,
+    });
+
+    let nonce = token_client.nonce(&admin_id);
+    let xfer_approval_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &token_id,
+        symbol!("xfer"),
+        (&admin_id, &nonce, &member_id, &BigInt::from_u32(&env, 30)),
+    );
+    contract_client.reward_m(&xfer_approval_sign, &member, &symbol!("talk"));
+
+    assert_eq!(contract_client.rep(&member), 45, "The first reward decayed to half");
+
+    token_client.with_source_account(&member).approve(
+        &Signature::Invoker,
+        &BigInt::zero(&env),
+        &Identifier::Contract(contract_id.clone()),
+        &BigInt::from_u32(&env, 30),
+    );
+
+    let nonce = contract_client.nonce(&admin_id);
+    let reverse_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("reverse"),
+        (&admin_id, &nonce, &reward),
+    );
+    contract_client.reverse(&reverse_sign, &nonce, &reward);
+
+    assert_eq!(contract_client.rep(&member), 30, "Only the decayed points of the reward are removed");
+}
+
+#[test]
+fn reversing_team_rewards_refunds_the_team() {
+    let env = Env::default();
+
+    let (admin_id, admin_sign) = ed25519::generate(&env);
+
+    let manager = env.accounts().generate();
+    let member = env.accounts().generate();
+    let member_id = Identifier::Account(member.clone());
+
+    let contract_id = env.register_contract(None, OrganizationContract);
+    let contract_client = OrganizationContractClient::new(&env, &contract_id);
+
+    let (token_id, token_client) = create_and_init_token_contract(&env, &admin_id);
+
+    let allowed_funds_to_issue = 1000;
+    let items = [(symbol!("talk"), 30), (symbol!("blog_post"), 25)];
+    let rewards: Map<Symbol, u32> = Map::from_array(&env, items);
+
+    contract_client.initialize(&admin_id, &symbol!("Kommit"), &rewards, &allowed_funds_to_issue, &token_id);
+
+    let nonce = token_client.nonce(&admin_id);
+    let approval_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &token_id,
+        symbol!("mint"),
+        (&admin_id, &nonce, &admin_id, &BigInt::from_u32(&env, allowed_funds_to_issue)),
+    );
+    contract_client.fund_c(&approval_sign);
+
+    // Team rewards are paid from the treasury through an allowance
+    let nonce = token_client.nonce(&admin_id);
+    let allowance = BigInt::from_u32(&env, 100);
+    let allowance_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &token_id,
+        symbol!("approve"),
+        (&admin_id, &nonce, &Identifier::Contract(contract_id.clone()), &allowance),
+    );
+    token_client.approve(&allowance_sign, &nonce, &Identifier::Contract(contract_id.clone()), &allowance);
+
+    add_member(&env, &contract_client, &contract_id, &admin_id, &admin_sign, &manager);
+    add_member(&env, &contract_client, &contract_id, &admin_id, &admin_sign, &member);
+
+    let nonce = contract_client.nonce(&admin_id);
+    let team_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("add_team"),
+        (&admin_id, &nonce, &symbol!("devops"), &manager, &50u32, &604800u64),
+    );
+    contract_client.add_team(&team_sign, &nonce, &symbol!("devops"), &manager, &50, &604800);
+
+    let nonce = contract_client.nonce(&admin_id);
+    let team_add_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("team_add"),
+        (&admin_id, &nonce, &symbol!("devops"), &member),
+    );
+    contract_client.team_add(&team_add_sign, &nonce, &symbol!("devops"), &member);
+
+    let nonce = contract_client.nonce(&admin_id);
+    let dispute_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("s_dispute"),
+        (&admin_id, &nonce, &500u64),
+    );
+    contract_client.s_dispute(&dispute_sign, &nonce, &500);
+
+    let reward_id = contract_client.with_source_account(&manager).team_rwd(&symbol!("devops"), &member, &symbol!("talk"));
+
+    token_client.with_source_account(&member).approve(
+        &Signature::Invoker,
+        &BigInt::zero(&env),
+        &Identifier::Contract(contract_id.clone()),
+        &BigInt::from_u32(&env, 30)
+    );
+
+    let nonce = contract_client.nonce(&admin_id);
+    let reverse_sign = ed25519::sign(
+        &env,
+        &admin_sign,
+        &contract_id,
+        symbol!("reverse"),
+        (&admin_id, &nonce, &reward_id),
+    );
+    contract_client.reverse(&reverse_sign, &nonce, &reward_id);
+
+    assert_eq!(contract_client.team_spent(&symbol!("devops")).total, 0, "Team spending is refunded");
+    assert_eq!(contract_client.team_left(&symbol!("devops")), 50, "Team budget is refunded");
+    assert_eq!(token_client.balance(&member_id), BigInt::zero(&env), "Reward was pulled back");
+}